@@ -1,5 +1,6 @@
 use std::rc::Rc;
 use std::collections::HashMap;
+use std::num::IntErrorKind;
 
 #[derive(Debug, PartialEq)]
 enum TokenType {
@@ -14,6 +15,7 @@ enum TokenType {
     DEFAULT,
     DIVISION,
     DOT,
+    ELLIPSIS,   // ...
     EOF,
     EQ,
     FLOAT,
@@ -23,12 +25,14 @@ enum TokenType {
     INCREMENT,
     INT,
     KEYWORD,
+    LABEL,      // 'outer
     LBRACE,
     LBRACKET,
     LPAREN,
+    LSHIFT,     // <<
     LT,
     LTE,
-    MATCHOR, // Match or |
+    MATCHOR, // Match or | (bitwise or outside match cases)
     MODULUS,
     MINUS,
     NE,
@@ -37,14 +41,16 @@ enum TokenType {
     PLUS,    
     RBRACE,
     RBRACKET,
-    REF,		// '&' -> pointer thing;
+    REF,		// '&' -> pointer thing; bitwise and
     RPAREN,
+    RSHIFT,     // >>
     SEMI,
     SOC,
-    STRING
+    STRING,
+    XOR         // ~
 }
 
-const KEYWORDS: [&str; 17] = [
+const KEYWORDS: [&str; 24] = [
     "let",
     "fn",
     "for",
@@ -61,25 +67,34 @@ const KEYWORDS: [&str; 17] = [
     "break",
     "continue",
     "use",
-    "as"
+    "as",
+    "yield",
+    "loop",
+    "do",
+    "try",
+    "catch",
+    "finally",
+    "throw"
 ];
 
 #[derive(Debug)]
 struct Token {
     token_type: TokenType,
-    token_value: String
+    token_value: String,
+    line: usize,        // 1-based line in the source file
 }
 
 struct Lexer {
     code: String,
     current_char: Option<char>,
     line: String,
+    line_number: usize,
     position: usize,
 }
 
 impl Lexer {
     fn new(code: String) -> Self {
-        Self { code, line: String::new(), current_char: None, position: 0 }
+        Self { code, line: String::new(), line_number: 0, current_char: None, position: 0 }
     }
 
     fn advance(&mut self) {
@@ -92,9 +107,10 @@ impl Lexer {
         let code = self.code.clone();
         let mut tokens: Vec<Token> = Vec::new();
         
-        for line in code.lines() {
+        for (number, line) in code.lines().enumerate() {
             //  init position to zero
             self.position = 0;
+            self.line_number = number + 1;
             self.line = line.to_string();
 
             //set current char
@@ -118,6 +134,9 @@ impl Lexer {
                         self.skip_comment();
                         continue;
                     },
+                    '\'' if self.is_label(tokens.last()) => {
+                        tokens.push(self.get_label());
+                    },
                     '\'' | '"' => {
                         tokens.push(self.get_string());
                     }
@@ -127,44 +146,50 @@ impl Lexer {
                     },
                     '(' => {
                         tokens.push(
-                            Token {token_type: TokenType::LPAREN, token_value: "(".to_string()}
+                            Token {token_type: TokenType::LPAREN, token_value: "(".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     ')' => {
                         tokens.push(
-                            Token {token_type: TokenType::RPAREN, token_value: ")".to_string()}
+                            Token {token_type: TokenType::RPAREN, token_value: ")".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     ',' => {
                         tokens.push(
-                            Token {token_type: TokenType::COMMA, token_value: ",".to_string()}
+                            Token {token_type: TokenType::COMMA, token_value: ",".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     ':' => {
                         tokens.push(
-                            Token {token_type: TokenType::COLON, token_value: ":".to_string()}
+                            Token {token_type: TokenType::COLON, token_value: ":".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     ';' => {
                         tokens.push(
-                            Token {token_type: TokenType::SEMI, token_value: ";".to_string()}
+                            Token {token_type: TokenType::SEMI, token_value: ";".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '>' => {
                         if self.peek() == Some('=') {
                             tokens.push(
-                                Token {token_type: TokenType::GTE, token_value: ">=".to_string()}
+                                Token {token_type: TokenType::GTE, token_value: ">=".to_string(), line: self.line_number}
+                            );
+                            self.advance();
+                            self.advance();
+                        } else if self.peek() == Some('>') {
+                            tokens.push(
+                                Token {token_type: TokenType::RSHIFT, token_value: ">>".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::GT, token_value: ">".to_string()}
+                                Token {token_type: TokenType::GT, token_value: ">".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -172,51 +197,64 @@ impl Lexer {
                     '<' => {
                         if self.peek() == Some('=') {
                             tokens.push(
-                                Token {token_type: TokenType::LTE, token_value: "<=".to_string()}
+                                Token {token_type: TokenType::LTE, token_value: "<=".to_string(), line: self.line_number}
+                            );
+                            self.advance();
+                            self.advance();
+                        } else if self.peek() == Some('<') {
+                            tokens.push(
+                                Token {token_type: TokenType::LSHIFT, token_value: "<<".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::LT, token_value: "<".to_string()}
+                                Token {token_type: TokenType::LT, token_value: "<".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
                     },
                     '[' => {
                         tokens.push(
-                            Token {token_type: TokenType::LBRACKET, token_value: "[".to_string()}
+                            Token {token_type: TokenType::LBRACKET, token_value: "[".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     ']' => {
                         tokens.push(
-                            Token {token_type: TokenType::RBRACKET, token_value: "]".to_string()}
+                            Token {token_type: TokenType::RBRACKET, token_value: "]".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '{' => {
                         tokens.push(
-                            Token {token_type: TokenType::LBRACE, token_value: "{".to_string()}
+                            Token {token_type: TokenType::LBRACE, token_value: "{".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '}' => {
                         tokens.push(
-                            Token {token_type: TokenType::RBRACE, token_value: "}".to_string()}
+                            Token {token_type: TokenType::RBRACE, token_value: "}".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '.' => {
-                        if self.peek() == Some('.') {
+                        if self.peek() == Some('.') && self.line.chars().nth(self.position + 1) == Some('.') {
+                            tokens.push(
+                                Token {token_type: TokenType::ELLIPSIS, token_value: "...".to_string(), line: self.line_number}
+                            );
+                            self.advance();
+                            self.advance();
+                            self.advance();
+                        } else if self.peek() == Some('.') {
                             tokens.push(
-                                Token {token_type: TokenType::DEFAULT, token_value: "..".to_string()}
+                                Token {token_type: TokenType::DEFAULT, token_value: "..".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::DOT, token_value: ".".to_string()}
+                                Token {token_type: TokenType::DOT, token_value: ".".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -224,13 +262,13 @@ impl Lexer {
                     '+' => {
                         if self.peek() == Some('+') {
                             tokens.push(
-                                Token {token_type: TokenType::INCREMENT, token_value: "++".to_string()}
+                                Token {token_type: TokenType::INCREMENT, token_value: "++".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::PLUS, token_value: "+".to_string()}
+                                Token {token_type: TokenType::PLUS, token_value: "+".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -238,55 +276,61 @@ impl Lexer {
                     '-' => {
                         if self.peek() == Some('-') {
                             tokens.push(
-                                Token {token_type: TokenType::DECREMENT, token_value: "--".to_string()}
+                                Token {token_type: TokenType::DECREMENT, token_value: "--".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::MINUS, token_value: "-".to_string()}
+                                Token {token_type: TokenType::MINUS, token_value: "-".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
                     },
                     '*' => {
                         tokens.push(
-                            Token {token_type: TokenType::ASTERISK, token_value: "*".to_string()}
+                            Token {token_type: TokenType::ASTERISK, token_value: "*".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '^' => {
                         tokens.push(
-                            Token {token_type: TokenType::CARET, token_value: "^".to_string()}
+                            Token {token_type: TokenType::CARET, token_value: "^".to_string(), line: self.line_number}
+                        );
+                        self.advance();
+                    },
+                    '~' => {
+                        tokens.push(
+                            Token {token_type: TokenType::XOR, token_value: "~".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '/' => {
                         tokens.push(
-                            Token {token_type: TokenType::DIVISION, token_value: "/".to_string()}
+                            Token {token_type: TokenType::DIVISION, token_value: "/".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '%' => {
                         tokens.push(
-                            Token {token_type: TokenType::MODULUS, token_value: "%".to_string()}
+                            Token {token_type: TokenType::MODULUS, token_value: "%".to_string(), line: self.line_number}
                         );
                         self.advance();
                     },
                     '=' => {
                         if self.peek() == Some('=') {
                             tokens.push(
-                                Token {token_type: TokenType::EQ, token_value: "==".to_string()}
+                                Token {token_type: TokenType::EQ, token_value: "==".to_string(), line: self.line_number}
                             );
                             self.advance();
                         } else if self.peek() == Some('>') {
                             tokens.push(
-                                Token {token_type: TokenType::ARROW, token_value: "=>".to_string()}
+                                Token {token_type: TokenType::ARROW, token_value: "=>".to_string(), line: self.line_number}
                             );
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::ASSIGN, token_value: "=".to_string()}
+                                Token {token_type: TokenType::ASSIGN, token_value: "=".to_string(), line: self.line_number}
                             );
                         }
                         self.advance();
@@ -294,13 +338,13 @@ impl Lexer {
                     '!' => {
                         if self.peek() == Some('=') {
                             tokens.push(
-                                Token {token_type: TokenType::NE, token_value: "!=".to_string()}
+                                Token {token_type: TokenType::NE, token_value: "!=".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::NEGATE, token_value: "!".to_string()}
+                                Token {token_type: TokenType::NEGATE, token_value: "!".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -308,13 +352,13 @@ impl Lexer {
                     '&' => {
 						if self.peek() == Some('&') {
                             tokens.push(
-                                Token {token_type: TokenType::AND, token_value: "&&".to_string()}
+                                Token {token_type: TokenType::AND, token_value: "&&".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::REF, token_value: "&".to_string()}
+                                Token {token_type: TokenType::REF, token_value: "&".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -322,13 +366,13 @@ impl Lexer {
                     '|' => {
 						if self.peek() == Some('|') {
                             tokens.push(
-                                Token {token_type: TokenType::OR, token_value: "||".to_string()}
+                                Token {token_type: TokenType::OR, token_value: "||".to_string(), line: self.line_number}
                             );
                             self.advance();
                             self.advance();
                         } else {
                             tokens.push(
-                                Token {token_type: TokenType::MATCHOR, token_value: "|".to_string()}
+                                Token {token_type: TokenType::MATCHOR, token_value: "|".to_string(), line: self.line_number}
                             );
                             self.advance();
                         }
@@ -340,7 +384,7 @@ impl Lexer {
                 }
             }
         }
-        tokens.push(Token {token_type: TokenType::EOF, token_value: String::from("EOF")});
+        tokens.push(Token {token_type: TokenType::EOF, token_value: String::from("EOF"), line: self.line_number});
         tokens        
     }
 
@@ -376,7 +420,36 @@ impl Lexer {
         }
 
         self.advance();
-        Token { token_type: TokenType::STRING, token_value: result }
+        Token { token_type: TokenType::STRING, token_value: result, line: self.line_number }
+    }
+
+    fn is_label(&self, previous: Option<&Token>) -> bool {
+        // 'outer: while (..)  or  break 'outer -> a loop label, not a string
+        let rest: String = self.line.chars().skip(self.position).collect();
+        let name: String = rest.chars().take_while(|chr| chr.is_alphanumeric() || *chr == '_').collect();
+        let after = &rest[name.len()..];
+
+        if name.is_empty() || after.starts_with('\'') {
+            return false;
+        }
+        if previous.is_some_and(|token| token.token_type == TokenType::KEYWORD && ["break", "continue"].contains(&token.token_value.as_str())) {
+            return true;
+        }
+        let Some(after) = after.strip_prefix(':') else { return false };
+        let keyword: String = after.trim_start().chars().take_while(|chr| chr.is_alphabetic()).collect();
+
+        // let text = 'done: for now'; stays a string
+        let starts_statement = previous.is_none_or(|token| {
+            [TokenType::SEMI, TokenType::LBRACE, TokenType::RBRACE].contains(&token.token_type)
+        });
+        ["while", "for", "loop", "do"].contains(&keyword.as_str()) && (starts_statement || !after.contains('\''))
+    }
+
+    fn get_label(&mut self) -> Token {
+        self.advance();
+        let Token { token_value, .. } = self.get_identifier();
+
+        Token { token_type: TokenType::LABEL, token_value, line: self.line_number }
     }
 
     fn peek(&mut self) -> Option<char> {
@@ -384,30 +457,97 @@ impl Lexer {
     }
     
     fn get_number(&mut self) -> Token {
+        // Supports 0x / 0o / 0b prefixes, `_` digit separators and
+        // scientific notation floats e.g 1_000, 0xFF, 1.5e-3
         let mut result = String::new();
         let mut dot_count = 0;
+        let mut exponent = false;
+
+        if self.current_char == Some('0') {
+            let radix = match self.peek() {
+                Some('x') | Some('X') => 16,
+                Some('o') | Some('O') => 8,
+                Some('b') | Some('B') => 2,
+                _ => 10
+            };
+
+            if radix != 10 {
+                self.advance();
+                self.advance();
+
+                while !self.current_char.is_none() &&
+                    (self.current_char.unwrap().is_alphanumeric() || self.current_char == Some('_')) {
+                    if self.current_char != Some('_') {
+                        result.push(self.current_char.unwrap());
+                    }
+                    self.advance();
+                }
+
+                let value: i32 = match i32::from_str_radix(&result, radix) {
+                    Ok(value) => value,
+                    Err(e) if *e.kind() == IntErrorKind::PosOverflow => {
+                        let prefix = match radix { 16 => "0x", 8 => "0o", _ => "0b" };
+                        println!("ParseError: Integer literal `{prefix}{result}` is out of range for Int (line {})", self.line_number);
+                        std::process::exit(1);
+                    },
+                    Err(_) => {
+                        println!("ParseError: Invalid base {radix} literal `{result}` (line {})", self.line_number);
+                        std::process::exit(1);
+                    }
+                };
+                return Token {
+                    token_type: TokenType::INT,
+                    token_value: value.to_string(),
+                    line: self.line_number
+                };
+            }
+        }
+
+        while !self.current_char.is_none() {
+            let chr = self.current_char.unwrap();
 
-        while !self.current_char.is_none() && self.current_char.unwrap().is_numeric() || self.current_char == Some('.') {
-            if self.current_char == Some('.') {
-                if dot_count == 1 {
+            if chr.is_numeric() {
+                result.push(chr);
+            } else if chr == '_' {
+                // digit separator
+            } else if chr == '.' && !exponent {
+                if dot_count == 1 || self.peek() == Some('.') {
                     break;
-                } else {
-                    dot_count += 1;
                 }
+                dot_count += 1;
+                result.push(chr);
+            } else if (chr == 'e' || chr == 'E') && !exponent {
+                let next = self.peek();
+                let signed = next == Some('+') || next == Some('-');
+                let digit = self.line.chars().nth(self.position + if signed { 1 } else { 0 });
+
+                if !digit.is_some_and(|c| c.is_numeric()) {
+                    break;
+                }
+                exponent = true;
+                result.push(chr);
+
+                if signed {
+                    self.advance();
+                    result.push(self.current_char.unwrap());
+                }
+            } else {
+                break;
             }
-            result.push(self.current_char.unwrap());
             self.advance();
         }
 
-        if dot_count == 0 {
+        if dot_count == 0 && !exponent {
             Token {
                 token_type: TokenType::INT,
-                token_value: result
+                token_value: result,
+                line: self.line_number
             }
         } else {
             Token {
                 token_type: TokenType::FLOAT,
-                token_value: result
+                token_value: result,
+                line: self.line_number
             }
         }
     }
@@ -423,12 +563,14 @@ impl Lexer {
         if KEYWORDS.contains(&&result[..]) {
             Token {
                 token_type: TokenType::KEYWORD,
-                token_value: result
+                token_value: result,
+                line: self.line_number
             }
         } else {
             Token {
                 token_type: TokenType::ID,
-                token_value: result
+                token_value: result,
+                line: self.line_number
             }
         }
     }
@@ -458,12 +600,13 @@ enum ASTNode {
     Float {value: f64},
     Str { value: String },
     None,
-    ID { name: String },
+    ID { name: String, line: usize },
     Bool { value: bool },
-    Var { name: Rc<ASTNode>, value: Option<Rc<ASTNode>>},
+    Var { name: Rc<ASTNode>, value: Option<Rc<ASTNode>>, annotation: Option<String>, eager: Option<bool>},    // eager: None follows the file's mode, Purity sets it for initializers with side effects
+    Assign { name: Rc<ASTNode>, value: Rc<ASTNode>},
     PropertyAccess { object: Rc<ASTNode>, property: Rc<ASTNode>},
     Index {object: Rc<ASTNode>, index: Rc<ASTNode>},
-    Flow { value: String },
+    Flow { value: String, label: Option<String>, result: Option<Rc<ASTNode>> },     // break / continue, optionally naming the loop; `break value` in a `loop`
 
     UnaryOperation { operand: Rc<ASTNode>, operator: String},
     BinaryOperation {left: Rc<ASTNode>, operation: String, right: Rc<ASTNode>},
@@ -474,12 +617,22 @@ enum ASTNode {
     Option { condition: Vec<ASTNode>, block: Vec<ASTNode>},
     Default,
 
-    While {condition: Rc<ASTNode>, body:Vec<ASTNode>},
-    For {loop_var: Rc<ASTNode>, object: Rc<ASTNode>, body:Vec<ASTNode>},
+    While {condition: Rc<ASTNode>, body:Vec<ASTNode>, label: Option<String>, body_first: bool},     // body_first -> do { } while (..);
+    Loop {body: Vec<ASTNode>, label: Option<String>},
+    For {loop_var: Rc<ASTNode>, object: Rc<ASTNode>, body:Vec<ASTNode>, label: Option<String>},
+
+    Try {body: Vec<ASTNode>, catches: Vec<ASTNode>, finally: Option<Vec<ASTNode>>},
+    Catch {name: Option<Rc<ASTNode>>, kind: Option<String>, block: Vec<ASTNode>},     // kind: None catches every error
+    Throw {value: Rc<ASTNode>, line: usize},
 
-    Function{name: Rc<ASTNode>, parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), block: Vec<ASTNode>},
+    Function{name: Rc<ASTNode>, parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), return_type: Option<String>, block: Vec<ASTNode>},
+    Lambda{parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), return_type: Option<String>, block: Vec<ASTNode>},
     FunctionCall{ name: Rc<ASTNode>, args: Vec<ASTNode>},
+    Constant{ value: Value},     // already evaluated e.g arguments passed to callbacks
+    Keyword{ name: String, value: Rc<ASTNode>},
+    Spread{ value: Rc<ASTNode>},
     Return {list: Vec<ASTNode>},
+    Yield {value: Rc<ASTNode>},
     
     Struct { name: Rc<ASTNode>, attributes: Vec<ASTNode> },
     Impl{name: Rc<ASTNode>, block: Vec<ASTNode>},
@@ -490,6 +643,7 @@ enum ASTNode {
 struct Parser {
     tokens: Vec<Token>,
     current_token: Token,
+    match_pattern: bool,    // `|` separates match options instead of being bitwise or
 }
 
 impl Parser {
@@ -498,8 +652,10 @@ impl Parser {
             tokens,
             current_token: Token {
                 token_type: TokenType::SOC,
-                token_value:String::from("SOC")
-            }
+                token_value:String::from("SOC"),
+                line: 0
+            },
+            match_pattern: false
         }
     }
 
@@ -548,19 +704,46 @@ impl Parser {
 					"impl" => return self.impl_declaration(),
 					"fn" => return self.function_declaration(),
                     "while" => return self.while_loop(),
+                    "loop" => return self.loop_statement(),
+                    "do" => return self.do_while(),
                     "for" => return self.for_loop(),
                     "if" => return self.if_statement(),
                     "match" => return self.match_statement(),
                     "let" => return self.variable_declaration(),
                     "rn" => return self.return_statement(),
+                    "yield" => return self.yield_statement(),
+                    "try" => return self.try_statement(),
+                    "throw" => return self.throw_statement(),
                     "use" => self.use_statement(),
                     _ => return self.expression_statement()
                 }
             },
+            TokenType::LABEL => return self.labeled_loop(),
+            TokenType::ID if self.tokens.last().is_some_and(|token| token.token_type == TokenType::ASSIGN) => {
+                return self.assignment();
+            },
+            // `lazy` and `eager` are only keywords in front of `let`
+            TokenType::ID if ["lazy", "eager"].contains(&self.current_token.token_value.as_str()) &&
+                self.tokens.last().is_some_and(|token| token.token_value == "let") => {
+                return self.strategy_declaration();
+            },
             _ => self.expression_statement()
         }
     }
 
+    fn assignment(&mut self) -> ASTNode {
+        // id "=" expression ";"
+        let name = self.current_token.token_value.clone();
+        let line = self.current_token.line;
+        self.eat(&TokenType::ID);
+        self.eat(&TokenType::ASSIGN);
+
+        let value = self.expression();
+        self.eat(&TokenType::SEMI);
+
+        ASTNode::Assign{ name: Rc::new(ASTNode::ID{ name, line }), value: Rc::new(value) }
+    }
+
     fn impl_declaration(&mut self) -> ASTNode {
 		self.eat(&TokenType::KEYWORD);
 		let name = self.id_statement();
@@ -585,6 +768,70 @@ impl Parser {
         return ASTNode::Use {modules};
     }
 
+    fn yield_statement(&mut self) -> ASTNode {
+        // "yield" expression ";"
+        self.eat(&TokenType::KEYWORD);
+        let value = self.expression();
+        self.eat(&TokenType::SEMI);
+
+        ASTNode::Yield{ value: Rc::new(value) }
+    }
+
+    fn try_statement(&mut self) -> ASTNode {
+        // "try" block catch* ("finally" block)?
+        self.eat(&TokenType::KEYWORD);
+        let body = self.block();
+
+        let mut catches: Vec<ASTNode> = vec![];
+        while self.current_token.token_value == "catch" {
+            catches.push(self.catch_clause());
+        }
+
+        let mut finally: Option<Vec<ASTNode>> = None;
+        if self.current_token.token_value == "finally" {
+            self.eat(&TokenType::KEYWORD);
+            finally = Some(self.block());
+        }
+
+        if catches.is_empty() && finally.is_none() {
+            eprintln!("ParseError: Expected `catch` or `finally` after the body of `try`");
+            std::process::exit(1);
+        }
+        return ASTNode::Try {body, catches, finally};
+    }
+
+    fn catch_clause(&mut self) -> ASTNode {
+        // "catch" ("(" id (":" type_annotation)? ")")? block
+        self.eat(&TokenType::KEYWORD);
+        let mut name: Option<Rc<ASTNode>> = None;
+        let mut kind: Option<String> = None;
+
+        if self.current_token.token_type == TokenType::LPAREN {
+            self.eat(&TokenType::LPAREN);
+            name = Some(Rc::new(self.id_statement()));
+
+            if self.current_token.token_type == TokenType::COLON {
+                self.eat(&TokenType::COLON);
+                kind = Some(self.type_annotation());
+            }
+            self.eat(&TokenType::RPAREN);
+        }
+
+        return ASTNode::Catch {name, kind, block: self.block()};
+    }
+
+    fn throw_statement(&mut self) -> ASTNode {
+        // "throw" expression ";"?
+        let line = self.current_token.line;
+        self.eat(&TokenType::KEYWORD);
+        let value = self.expression();
+
+        if self.current_token.token_type == TokenType::SEMI {
+            self.eat(&TokenType::SEMI);
+        }
+        ASTNode::Throw{ value: Rc::new(value), line }
+    }
+
     fn return_statement(&mut self) -> ASTNode {
         // "return" expression_list
         self.eat(&TokenType::KEYWORD);
@@ -622,10 +869,10 @@ impl Parser {
         // expression "=>" block
         let mut cases: Vec<ASTNode> = vec![];
         
-        let mut condition = vec![self.expression()];
+        let mut condition = vec![self.pattern()];
         while self.current_token.token_type == TokenType::MATCHOR {
 		    self.eat(&TokenType::MATCHOR);
-		    condition.push(self.expression());
+		    condition.push(self.pattern());
         }
         self.eat(&TokenType::ARROW);
         cases.push(ASTNode::Option {condition, block: self.block()});
@@ -640,11 +887,11 @@ impl Parser {
                 cases.push( self.expression());
                 continue
             }
-            let mut condition = vec![self.expression()];
+            let mut condition = vec![self.pattern()];
             
 			while self.current_token.token_type == TokenType::MATCHOR {
 				self.eat(&TokenType::MATCHOR);
-				condition.push(self.expression());
+				condition.push(self.pattern());
 			}
             self.eat(&TokenType::ARROW);
             cases.push(ASTNode::Option {condition, block: self.block()});
@@ -653,8 +900,17 @@ impl Parser {
         return cases;
     }
 
+    fn pattern(&mut self) -> ASTNode {
+        // expression where `|` is left for separating options
+        self.match_pattern = true;
+        let pattern = self.expression();
+        self.match_pattern = false;
+
+        pattern
+    }
+
     fn if_statement(&mut self) -> ASTNode {
-        // "if" "(" expression ")" block else_clause
+        // "if" "(" expression ")" block ("else" (if_statement | block))?
         self.eat(&TokenType::KEYWORD);
         
         self.eat(&TokenType::LPAREN);
@@ -668,7 +924,13 @@ impl Parser {
             self.current_token.token_value == "else"
         {
             self.eat(&TokenType::KEYWORD);
-            Some(self.block())
+
+            // else if (..) { } -> the else block holds just the next `if`
+            if self.current_token.token_type == TokenType::KEYWORD && self.current_token.token_value == "if" {
+                Some(vec![self.if_statement()])
+            } else {
+                Some(self.block())
+            }
         } else {
             None
         };
@@ -676,19 +938,19 @@ impl Parser {
     }
 
     fn for_loop(&mut self) -> ASTNode {
-        // "for" "(" id_statement ":" id_statement (",", id_statement)* ")" block
+        // "for" "(" id_statement ":" expression ")" block
         self.eat(&TokenType::KEYWORD);
         
         self.eat(&TokenType::LPAREN);
-        let obj = self.id_statement();
+        let loop_var = self.id_statement();
 
         self.eat(&TokenType::COLON);
-        let loop_var = self.id_statement();
+        let obj = self.expression();
         self.eat(&TokenType::RPAREN);
 
         let body = self.block();
 
-        return ASTNode::For{loop_var: Rc::new(loop_var), object: Rc::new(obj), body};
+        return ASTNode::For{loop_var: Rc::new(loop_var), object: Rc::new(obj), body, label: None};
     }
 
     fn while_loop(&mut self) -> ASTNode {
@@ -701,18 +963,70 @@ impl Parser {
 
         let body = self.block();
 
-        return ASTNode::While {condition: Rc::new(condition), body};
+        return ASTNode::While {condition: Rc::new(condition), body, label: None, body_first: false};
+    }
+
+    fn do_while(&mut self) -> ASTNode {
+        // "do" block "while" "(" expression ")" [";"]
+        self.eat(&TokenType::KEYWORD);
+        let body = self.block();
+
+        if self.current_token.token_value != "while" {
+            eprintln!("ParseError: Expected `while` after the body of `do`");
+            std::process::exit(1);
+        }
+        self.eat(&TokenType::KEYWORD);
+
+        self.eat(&TokenType::LPAREN);
+        let condition = self.expression();
+        self.eat(&TokenType::RPAREN);
+        if self.current_token.token_type == TokenType::SEMI {
+            self.eat(&TokenType::SEMI);
+        }
+
+        return ASTNode::While {condition: Rc::new(condition), body, label: None, body_first: true};
+    }
+
+    fn loop_statement(&mut self) -> ASTNode {
+        // "loop" block
+        self.eat(&TokenType::KEYWORD);
+        let body = self.block();
+
+        return ASTNode::Loop {body, label: None};
+    }
+
+    fn labeled_loop(&mut self) -> ASTNode {
+        // label ":" (while_loop | for_loop | loop_statement | do_while)
+        let name = self.current_token.token_value.clone();
+        self.eat(&TokenType::LABEL);
+        self.eat(&TokenType::COLON);
+
+        match self.statement() {
+            ASTNode::While{condition, body, body_first, ..} => ASTNode::While{ condition, body, label: Some(name), body_first },
+            ASTNode::Loop{body, ..} => ASTNode::Loop{ body, label: Some(name) },
+            ASTNode::For{loop_var, object, body, ..} => ASTNode::For{ loop_var, object, body, label: Some(name) },
+            _ => {
+                eprintln!("ParseError: The label '{name} must be followed by a loop");
+                std::process::exit(1);
+            }
+        }
     }
 
     fn function_declaration(&mut self) -> ASTNode {
-        // "func" id_statement parameters block
+        // "func" id_statement parameters (":" type_annotation)? block
         self.eat(&TokenType::KEYWORD);
         
         let name = self.id_statement();
         let parameters = self.parameters();
+
+        let mut return_type: Option<String> = None;
+        if self.current_token.token_type == TokenType::COLON {
+            self.eat(&TokenType::COLON);
+            return_type = Some(self.type_annotation());
+        }
         let block = self.block();
 
-        return ASTNode::Function{name: Rc::new(name), parameters, block};
+        return ASTNode::Function{name: Rc::new(name), parameters, return_type, block};
     }
 
     fn parameters(&mut self) -> (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>) {
@@ -731,22 +1045,22 @@ impl Parser {
                 self.eat(&TokenType::RPAREN);
                 return (None, None);
             }
-            out_.push(self.expression());
+            out_.push(self.parameter());
 
             while self.current_token.token_type == TokenType::COMMA {
                 self.eat(&TokenType::COMMA);
-                out_.push(self.expression());
+                out_.push(self.parameter());
             }
 
             self.eat(&TokenType::RPAREN);
             return (None, Some(out_));
         }
         
-        in_.push(self.id_statement());
+        in_.push(self.parameter());
 
         while self.current_token.token_type == TokenType::COMMA {
             self.eat(&TokenType::COMMA);
-            in_.push(self.id_statement());
+            in_.push(self.parameter());
         }
         
         if self.current_token.token_type == TokenType::COLON {
@@ -756,11 +1070,11 @@ impl Parser {
                 self.eat(&TokenType::RPAREN);
                 return (Some(in_), None);
             }
-            out_.push(self.expression());
+            out_.push(self.parameter());
 
             while self.current_token.token_type == TokenType::COMMA {
                 self.eat(&TokenType::COMMA);
-                out_.push(self.expression());
+                out_.push(self.parameter());
             }
 
             self.eat(&TokenType::RPAREN);
@@ -770,6 +1084,61 @@ impl Parser {
         return (Some(in_), None);
     }
 
+    fn parameter(&mut self) -> ASTNode {
+        // "..." id_statement
+        // | id_statement (":" type_annotation)? ("=" expression)?
+        // `:` followed by a capitalised name is a type, otherwise it starts the output parameters
+        if self.current_token.token_type == TokenType::ELLIPSIS {
+            self.eat(&TokenType::ELLIPSIS);
+            let name = self.id_statement();
+
+            if ![TokenType::RPAREN, TokenType::COLON].contains(&self.current_token.token_type) {
+                println!("ParseError: A rest parameter `...` must be the last input parameter");
+                std::process::exit(1);
+            }
+            return ASTNode::Spread{ value: Rc::new(name) };
+        }
+        let name = self.id_statement();
+        let mut annotation: Option<String> = None;
+        let mut value: Option<Rc<ASTNode>> = None;
+
+        if self.current_token.token_type == TokenType::COLON && self.next_is_type() {
+            self.eat(&TokenType::COLON);
+            annotation = Some(self.type_annotation());
+        }
+        if self.current_token.token_type == TokenType::ASSIGN {
+            self.eat(&TokenType::ASSIGN);
+            value = Some(Rc::new(self.expression()));
+        }
+
+        if annotation.is_none() && value.is_none() {
+            return name;
+        }
+        ASTNode::Var{ name: Rc::new(name), value, annotation, eager: None }
+    }
+
+    fn next_is_type(&self) -> bool {
+        match self.tokens.last() {
+            Some(token) => {
+                (token.token_type == TokenType::ID && token.token_value.starts_with(char::is_uppercase)) ||
+                (token.token_type == TokenType::KEYWORD && token.token_value == "None")
+            },
+            None => false
+        }
+    }
+
+    fn type_annotation(&mut self) -> String {
+        // Int | Float | Bool | Str | None | Vector | Any | struct name
+        let type_name = self.current_token.token_value.clone();
+
+        if self.current_token.token_type == TokenType::KEYWORD && type_name == "None" {
+            self.eat(&TokenType::KEYWORD);
+        } else {
+            self.eat(&TokenType::ID);
+        }
+        type_name
+    }
+
     fn struct_declaration(&mut self) -> ASTNode {
 		// class name parent_classes block
 		self.eat(&TokenType::KEYWORD);
@@ -811,37 +1180,55 @@ impl Parser {
     }
     
     fn variable_declaration(&mut self) -> ASTNode {
-        // let name = value;
+        // let name (":" type)? = value;
         // or
-        // let name;
+        // let name (":" type)?;
         self.eat(&TokenType::KEYWORD);
         let name = self.id_statement();
 
+        let mut annotation: Option<String> = None;
+        if self.current_token.token_type == TokenType::COLON {
+            self.eat(&TokenType::COLON);
+            annotation = Some(self.type_annotation());
+        }
+
         if self.current_token.token_type == TokenType::SEMI {
             self.eat(&TokenType::SEMI);
-            return ASTNode::Var{ name: Rc::new(name), value: None };
+            return ASTNode::Var{ name: Rc::new(name), value: None, annotation, eager: None };
         }
         self.eat(&TokenType::ASSIGN);
 
         let value = self.expression_statement();
         self.eat(&TokenType::SEMI);
         
-        return ASTNode::Var{ name: Rc::new(name), value: Some(Rc::new(value)) };
+        return ASTNode::Var{ name: Rc::new(name), value: Some(Rc::new(value)), annotation, eager: None };
+    }
+
+    fn strategy_declaration(&mut self) -> ASTNode {
+        // ("lazy" | "eager") variable_declaration
+        let eager = self.current_token.token_value == "eager";
+        self.eat(&TokenType::ID);
+
+        match self.variable_declaration() {
+            ASTNode::Var{ name, value, annotation, .. } => ASTNode::Var{ name, value, annotation, eager: Some(eager) },
+            declaration => declaration
+        }
     }
 
     fn id_statement(&mut self) -> ASTNode {
         let mut var: ASTNode;
         let name = self.current_token.token_value.clone();
-        
+        let line = self.current_token.line;
 
         self.eat(&TokenType::ID);
-        var = ASTNode::ID{ name };
+        var = ASTNode::ID{ name, line };
 
         while self.current_token.token_type == TokenType::DOT {
             self.eat(&TokenType::DOT);
             let property = Rc::new(
                 ASTNode::ID {
-                    name: self.current_token.token_value.clone()
+                    name: self.current_token.token_value.clone(),
+                    line: self.current_token.line
                 }
             );
             self.eat(&TokenType::ID);
@@ -880,7 +1267,7 @@ impl Parser {
     }
 
     fn comparison_expression(&mut self) -> ASTNode {
-        let mut result = self.power_expression();
+        let mut result = self.bitwise_expression();
         let mut operation;
 
         while [TokenType::LT, TokenType::LTE, TokenType::GT, TokenType::GTE, TokenType::EQ, TokenType::NE]
@@ -914,6 +1301,51 @@ impl Parser {
             result = ASTNode::BinaryOperation {
                 left: Rc::new(result),
                 operation: operation.to_string(),
+                right: Rc::new(self.bitwise_expression())
+            };
+        }
+        result
+    }
+
+    fn bitwise_expression(&mut self) -> ASTNode {
+        // shift_expression (("&" | "|" | "~") shift_expression)*
+        let mut result = self.shift_expression();
+        let mut operation;
+
+        loop {
+            match self.current_token.token_type {
+                TokenType::REF => operation = "&",
+                TokenType::MATCHOR if !self.match_pattern => operation = "|",
+                TokenType::XOR => operation = "~",
+                _ => break
+            }
+            self.advance();
+
+            result = ASTNode::BinaryOperation {
+                left: Rc::new(result),
+                operation: operation.to_string(),
+                right: Rc::new(self.shift_expression())
+            };
+        }
+        result
+    }
+
+    fn shift_expression(&mut self) -> ASTNode {
+        // power_expression (("<<" | ">>") power_expression)*
+        let mut result = self.power_expression();
+        let mut operation;
+
+        while [TokenType::LSHIFT, TokenType::RSHIFT].contains(&self.current_token.token_type) {
+            if self.current_token.token_type == TokenType::LSHIFT {
+                self.eat(&TokenType::LSHIFT);
+                operation = "<<".to_string();
+            } else {
+                self.eat(&TokenType::RSHIFT);
+                operation = ">>".to_string();
+            }
+            result = ASTNode::BinaryOperation {
+                left: Rc::new(result),
+                operation,
                 right: Rc::new(self.power_expression())
             };
         }
@@ -963,7 +1395,7 @@ impl Parser {
     }
 
     fn term(&mut self) -> ASTNode {
-        let mut result = self.primary();
+        let mut result = self.unary();
         let mut operation;
 
         while [TokenType::ASTERISK, TokenType::DIVISION].contains(&self.current_token.token_type) {
@@ -977,26 +1409,53 @@ impl Parser {
             result = ASTNode::BinaryOperation {
                 left: Rc::new(result),
                 operation,
-                right: Rc::new(self.primary())
+                right: Rc::new(self.unary())
             };
         }
         result
     }
 
+    fn unary(&mut self) -> ASTNode {
+        // ("+" | "-" | "!" | "~") unary | primary
+        let operator = match self.current_token.token_type {
+            TokenType::PLUS => "+",
+            TokenType::MINUS => "-",
+            TokenType::NEGATE => "!",
+            TokenType::XOR => "~",
+            _ => return self.primary()
+        };
+        self.advance();
+
+        ASTNode::UnaryOperation {
+            operand: Rc::new(self.unary()),
+            operator: operator.to_string()
+        }
+    }
+
     fn primary(&mut self) -> ASTNode {
         if self.current_token.token_type == TokenType::ID {
-            let var = self.id_statement();
+            let mut var = self.id_statement();
 
-            if [TokenType::LPAREN, TokenType::LBRACKET, TokenType::INCREMENT, TokenType::DECREMENT]
-                .contains(&self.current_token.token_type) {
+            // Calls and indexes chain -> make_adder(1)(2), grid[0][1]
+            while [TokenType::LPAREN, TokenType::LBRACKET].contains(&self.current_token.token_type) {
+                var = self.factor_suffix(var);
+            }
+            if [TokenType::INCREMENT, TokenType::DECREMENT].contains(&self.current_token.token_type) {
                 return self.factor_suffix(var);
             }
             
             return var;
         } else if self.current_token.token_type == TokenType::INT {
-            let value: i32 = self.current_token.token_value.trim().parse()
-                .expect(format!("Parse Error: Expected Integer but found\n Value > {}", self.current_token.token_value)
-                .as_str());
+            let value: i32 = match self.current_token.token_value.trim().parse() {
+                Ok(value) => value,
+                Err(_) => {
+                    println!(
+                        "ParseError: Integer literal `{}` is out of range for Int (line {})",
+                        self.current_token.token_value, self.current_token.line
+                    );
+                    std::process::exit(1);
+                }
+            };
             self.eat(&TokenType::INT);
             return ASTNode::Integer{value};
         } else if self.current_token.token_type == TokenType::FLOAT {
@@ -1009,23 +1468,76 @@ impl Parser {
             let value = self.current_token.token_value.clone();
             self.eat(&TokenType::STRING);
             return ASTNode::Str {value};
+        } else if self.current_token.token_type == TokenType::LABEL {
+            // let cell = 'grid: loop { .. break 'grid value };
+            return self.labeled_loop();
         } else if self.current_token.token_type == TokenType::KEYWORD {
+            // if as an expression -> let label = if (x > 0) { "pos" } else { "neg" };
+            if self.current_token.token_value == "if" {
+                return self.if_statement();
+            }
+            // let found = loop { .. break value };
+            if self.current_token.token_value == "loop" {
+                return self.loop_statement();
+            }
+            // let port = try { int(text) } catch { 8080 };
+            if self.current_token.token_value == "try" {
+                return self.try_statement();
+            }
             let value = self.current_token.token_value.clone();
+            let line = self.current_token.line;
             self.eat(&TokenType::KEYWORD);
 
             if value == "None" {
 				return ASTNode::None;
+			} else if value == "fn" {
+				// Anonymous function -> fn(x) { rn x * 2; }
+				let parameters = self.parameters();
+				let mut return_type: Option<String> = None;
+
+				if self.current_token.token_type == TokenType::COLON {
+					self.eat(&TokenType::COLON);
+					return_type = Some(self.type_annotation());
+				}
+				return ASTNode::Lambda{ parameters, return_type, block: self.block() };
 			} else if value == "True" {
 				return ASTNode::Bool { value: true };
 			} else if value == "False" {
 				return ASTNode::Bool { value: false };
 			}
-            return ASTNode::Flow {value};
+            if value == "break" || value == "continue" {
+                let label = match self.current_token.token_type {
+                    TokenType::LABEL => {
+                        let label = self.current_token.token_value.clone();
+                        self.eat(&TokenType::LABEL);
+                        Some(label)
+                    },
+                    _ => None
+                };
+                // break value -> only when the value starts on the same line
+                let ends = [TokenType::RBRACE, TokenType::SEMI, TokenType::COMMA, TokenType::EOF];
+                let result = if value == "break" && self.current_token.line == line && !ends.contains(&self.current_token.token_type) {
+                    Some(Rc::new(self.expression()))
+                } else {
+                    None
+                };
+                return ASTNode::Flow {value, label, result};
+            }
+            return ASTNode::Flow {value, label: None, result: None};
+        } else if [TokenType::MATCHOR, TokenType::OR].contains(&self.current_token.token_type) {
+            return self.lambda();
         } else if self.current_token.token_type == TokenType::LPAREN {
             self.eat(&TokenType::LPAREN);
-            let expr = self.expression();
+            let match_pattern = self.match_pattern;
+            self.match_pattern = false;
+            let mut expr = self.expression();
+            self.match_pattern = match_pattern;
 
             self.eat(&TokenType::RPAREN);
+
+            while self.current_token.token_type == TokenType::LPAREN {
+                expr = self.factor_suffix(expr);
+            }
             return expr;
         } else if self.current_token.token_type == TokenType::LBRACKET {
 			self.eat(&TokenType::LBRACKET);
@@ -1036,10 +1548,10 @@ impl Parser {
                 return ASTNode::ExpressionList{ list: expr_list };
             }
             
-            expr_list.push(self.expression());
+            expr_list.push(self.list_item());
             while self.current_token.token_type == TokenType::COMMA {
 				self.eat(&TokenType::COMMA);
-                expr_list.push(self.expression());
+                expr_list.push(self.list_item());
             }
             self.eat(&TokenType::RBRACKET);
             return ASTNode::ExpressionList{ list: expr_list };
@@ -1049,7 +1561,7 @@ impl Parser {
 
             while self.current_token.token_type == TokenType::MATCHOR {
 				self.eat(&TokenType::MATCHOR);
-				condition.push(self.expression());
+				condition.push(self.pattern());
 			}
             self.eat(&TokenType::ARROW);
 			
@@ -1057,24 +1569,6 @@ impl Parser {
                 condition,
                 block: self.block()
             }
-        } else if self.current_token.token_type == TokenType::PLUS {
-            self.eat(&TokenType::PLUS);
-            ASTNode::UnaryOperation {
-                operand: Rc::new(self.expression()),
-                operator: "+".to_string()
-            }
-        } else if self.current_token.token_type == TokenType::MINUS {
-            self.eat(&TokenType::MINUS);
-            ASTNode::UnaryOperation {
-                operand: Rc::new(self.expression()),
-                operator: "-".to_string()
-            }
-        } else if self.current_token.token_type == TokenType::NEGATE {
-            self.eat(&TokenType::NEGATE);
-            ASTNode::UnaryOperation {
-                operand: Rc::new(self.expression()),
-                operator: "!".to_string()
-            }
         } else {
             println!("ParseError: Unexpected Token");
             println!("Token > {:?}", &self.current_token);
@@ -1084,6 +1578,34 @@ impl Parser {
         
     }
 
+    fn lambda(&mut self) -> ASTNode {
+        // "|" parameter ("," parameter)* "|" expression
+        // | "||" expression
+        let mut params: Vec<ASTNode> = vec![];
+
+        if self.current_token.token_type == TokenType::OR {
+            self.eat(&TokenType::OR);
+        } else {
+            self.eat(&TokenType::MATCHOR);
+            let match_pattern = self.match_pattern;
+            self.match_pattern = true;
+
+            while self.current_token.token_type != TokenType::MATCHOR {
+                params.push(self.parameter());
+
+                if self.current_token.token_type == TokenType::COMMA {
+                    self.eat(&TokenType::COMMA);
+                }
+            }
+            self.match_pattern = match_pattern;
+            self.eat(&TokenType::MATCHOR);
+        }
+        let body = self.expression();
+        let parameters = (if params.is_empty() { None } else { Some(params) }, None);
+
+        ASTNode::Lambda{ parameters, return_type: None, block: vec![ASTNode::Return{ list: vec![body] }] }
+    }
+
     fn factor_suffix(&mut self, expression: ASTNode) -> ASTNode {
         match self.current_token.token_type {
             TokenType::LPAREN => {
@@ -1131,377 +1653,2647 @@ impl Parser {
         if self.current_token.token_type == TokenType::RPAREN {
             return args;
         }
-        args.push(self.expression());
+        args.push(self.argument());
 
         while self.current_token.token_type == TokenType::COMMA {
 			self.eat(&TokenType::COMMA);
-            args.push(self.expression());
+            args.push(self.argument());
         }
         return args;
     }
-}
 
-#[derive(Debug, Clone)]
-enum LazyResult {
-	Null, //No return used int
-	Int(i32),
-	Float(f64),
-	Str(String),
-	Bool(bool),
-	List(Vec<Value>),
-	None,      // Used in Mar
-	Expression{expr: Rc<ASTNode>},
-}
+    fn list_item(&mut self) -> ASTNode {
+        // "..." expression | expression
+        if self.current_token.token_type != TokenType::ELLIPSIS {
+            return self.expression();
+        }
+        self.eat(&TokenType::ELLIPSIS);
 
-struct Executor {
-    ast: Vec<ASTNode>,
-    current_scope: HashMap<String, Option<LazyResult>>,
-    scopes: Vec<HashMap<String, Option<LazyResult>>>,
-    functions: Vec<HashMap<String, ((Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), Vec<ASTNode>)>>,
-	local_variables: Vec<String>,
-    return_value: Option<Value>,
-    structs: HashMap<String, Vec<ASTNode>>,
-    structs_impl: HashMap<String, Vec<ASTNode>>,
-    break_loop: bool,
-    continue_loop: bool,
-    
-}
+        ASTNode::Spread{ value: Rc::new(self.expression()) }
+    }
 
-const BUILTIN_FUNCTIONS: [&str; 2] = [
-	"print",
-	"println",
-];
+    fn argument(&mut self) -> ASTNode {
+        // "..." expression | (ID "=")? expression
+        if self.current_token.token_type == TokenType::ELLIPSIS {
+            return self.list_item();
+        }
+        let keyword = self.current_token.token_type == TokenType::ID &&
+            self.tokens.last().is_some_and(|token| token.token_type == TokenType::ASSIGN);
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
-struct Value {
-	int_value: Option<i32>,
-	float_value: Option<f64>,
-	bool_value: Option<bool>,
-	string_value: Option<String>,
-	list_value: Option<Vec<Value>>,
-	value_type: u8
-	/*
-	 * 0   - ----- - int
-	 * 1   - ----- - float
- 	 * 2   - ----- - bool
- 	 * 3   - ----- - string
- 	 * 4   - ----- - None
- 	 * 5   - ----- - list
- 	 * 127 - ----- - Undefined
- 	 */
+        if !keyword {
+            return self.expression();
+        }
+        let name = self.current_token.token_value.clone();
+        self.eat(&TokenType::ID);
+        self.eat(&TokenType::ASSIGN);
+
+        ASTNode::Keyword{ name, value: Rc::new(self.expression()) }
+    }
 }
 
-use std::fmt::Display;
-use std::fmt::Formatter;
+struct TypeChecker {
+	// Pre-execution pass over the AST. Only annotated bindings, literals and builtins
+	// have a known type, anything else is unknown and is left for the Executor.
+	// Operator mismatches are only reported when an annotated type is involved
+	scopes: Vec<HashMap<String, String>>,
+	functions: Vec<HashMap<String, (Vec<(String, Option<String>)>, Option<String>)>>,    // signatures declared in each enclosing block
+	structs: Vec<String>,
+	return_types: Vec<Option<String>>,
+	context: String,
+	errors: Vec<String>,
+}
 
-impl Display for Value {
-	fn fmt(&self, _: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-		let res = match self.value_type {
-			0 => format!("{}", self.int_value.unwrap()),
-			1 => format!("{}", self.float_value.unwrap()),
-			2 => format!("{}", self.bool_value.unwrap()),
-			3 => format!("{}", self.string_value.clone().unwrap()),
-			4 => format!("None"),
-			5 => format!("{:?}", self.list_value.clone().unwrap()),
-			127 => {
-				 println!("(Int)Undefined Value Type");
-				 std::process::exit(1);
-			 },
+impl TypeChecker {
+	fn new() -> Self {
+		Self {
+			scopes: vec![HashMap::new()],
+			functions: vec![],
+			structs: vec![],
+			return_types: vec![],
+			context: String::from("<main>"),
+			errors: vec![],
+		}
+	}
+
+	fn check(&mut self, ast: &Vec<ASTNode>) -> Vec<String> {
+		for statement in ast {
+			if let ASTNode::Struct{name, ..} = statement {
+				if let ASTNode::ID{ref name, ..} = **name {
+					self.structs.push(name.to_string());
+				}
+			}
+		}
+		self.check_block(ast);
+
+		return self.errors.clone();
+	}
+
+	fn check_block(&mut self, block: &Vec<ASTNode>) {
+		// Signatures first so that calls before declarations are checked too,
+		// a function declared in a block is only known inside it
+		let mut signatures = HashMap::new();
+
+		for statement in block {
+			if let ASTNode::Function{name, parameters, return_type, ..} = statement {
+				if let ASTNode::ID{ref name, ..} = **name {
+					let (input, _) = parameters;
+					let param_types = input.iter().flatten().map(|param| match param {
+						ASTNode::Var{name, annotation, ..} => (self.param_name(name), annotation.clone()),
+						_ => (self.param_name(param), None)
+					}).collect();
+					signatures.insert(name.to_string(), (param_types, return_type.clone()));
+				}
+			}
+		}
+		self.functions.push(signatures);
+
+		for statement in block {
+			self.check_statement(statement);
+		}
+		self.functions.pop();
+	}
+
+	fn signature(&self, name: &str) -> Option<&(Vec<(String, Option<String>)>, Option<String>)> {
+		self.functions.iter().rev().find_map(|signatures| signatures.get(name))
+	}
+
+	fn check_statement(&mut self, statement: &ASTNode) {
+		match statement {
+			ASTNode::Var{name, value, annotation, ..} => {
+				let ASTNode::ID{ref name, ..} = **name else { return };
+				// `let` re-declares in the current scope only, like in the Executor
+				let declared = match annotation {
+					Some(annotation) => Some(annotation.clone()),
+					None => self.scopes.last().unwrap().get(name).cloned()
+				};
+				let context = std::mem::replace(&mut self.context, format!("let {name}"));
+
+				if let Some(value) = value {
+					let found = self.infer(value);
+
+					if let (Some(declared), Some(found)) = (&declared, &found) {
+						if !self.compatible(declared, found) {
+							self.error(format!("`{name}` is declared as `{declared}` but assigned `{found}`"));
+						}
+					}
+				}
+				self.context = context;
+
+				if let Some(annotation) = annotation {
+					self.scopes.last_mut().unwrap().insert(name.to_string(), annotation.clone());
+				}
+			},
+			ASTNode::Assign{name, value} => {
+				let ASTNode::ID{ref name, ..} = **name else { return };
+				let context = std::mem::replace(&mut self.context, format!("{name} = .."));
+				let found = self.infer(value);
+
+				if let (Some(declared), Some(found)) = (self.lookup(name), found) {
+					if !self.compatible(&declared, &found) {
+						self.error(format!("`{name}` is declared as `{declared}` but assigned `{found}`"));
+					}
+				}
+				self.context = context;
+			},
+			ASTNode::Function{name, parameters, return_type, block} => {
+				let name = match **name {
+					ASTNode::ID{ref name, ..} => name.to_string(),
+					_ => String::new()
+				};
+				let context = std::mem::replace(&mut self.context, format!("fn {name}"));
+				self.scopes.push(HashMap::new());
+
+				// Parameters are checked like `let` declarations in the function scope,
+				// unannotated ones shadow any outer binding as `Any`
+				for param in parameters.0.iter().flatten().chain(parameters.1.iter().flatten()) {
+					match param {
+						ASTNode::Var{name, annotation, ..} => {
+							self.check_statement(param);
+
+							if annotation.is_none() {
+								let name = self.param_name(name);
+								self.scopes.last_mut().unwrap().insert(name, String::from("Any"));
+							}
+						},
+						ASTNode::Spread{..} => {
+							let name = self.param_name(param);
+							self.scopes.last_mut().unwrap().insert(name, String::from("Vector"));
+						},
+						_ => {
+							let name = self.param_name(param);
+							self.scopes.last_mut().unwrap().insert(name, String::from("Any"));
+						}
+					}
+				}
+				self.return_types.push(return_type.clone());
+
+				self.check_block(block);
+
+				self.return_types.pop();
+				self.scopes.pop();
+				self.context = context;
+			},
+			ASTNode::Yield{value} => {
+				self.infer(value);
+			},
+			ASTNode::Return{list} => {
+				let found = match list.len() {
+					0 => Some(String::from("None")),
+					1 => self.infer(&list[0]),
+					_ => {
+						for expression in list {
+							self.infer(expression);
+						}
+						Some(String::from("Vector"))
+					}
+				};
+
+				if let (Some(Some(expected)), Some(found)) = (self.return_types.last().cloned(), found) {
+					if !self.compatible(&expected, &found) {
+						self.error(format!("Expected return type `{expected}`, found `{found}`"));
+					}
+				}
+			},
+			ASTNode::If{condition, if_block, else_block} => {
+				self.infer(condition);
+				self.scoped_block(if_block);
+
+				if let Some(else_block) = else_block {
+					self.scoped_block(else_block);
+				}
+			},
+			ASTNode::While{condition, body, ..} => {
+				self.infer(condition);
+				self.scoped_block(body);
+			},
+			ASTNode::Loop{body, ..} => self.scoped_block(body),
+			ASTNode::Try{body, catches, finally} => {
+				self.scoped_block(body);
+
+				for clause in catches {
+					if let ASTNode::Catch{name, block, ..} = clause {
+						self.scopes.push(HashMap::new());
+						if let Some(name) = name {
+							let name = self.param_name(name);
+							self.scopes.last_mut().unwrap().insert(name, String::from("Any"));
+						}
+						self.check_block(block);
+						self.scopes.pop();
+					}
+				}
+				if let Some(finally) = finally {
+					self.scoped_block(finally);
+				}
+			},
+			ASTNode::Throw{value, ..} => {
+				self.infer(value);
+			},
+			ASTNode::For{loop_var, object, body, ..} => {
+				self.infer(object);
+				self.scopes.push(HashMap::new());
+
+				let name = self.param_name(loop_var);
+				self.scopes.last_mut().unwrap().insert(name, String::from("Any"));
+				self.check_block(body);
+
+				self.scopes.pop();
+			},
+			ASTNode::Match{option, cases} => {
+				self.infer(option);
+
+				for case in cases {
+					if let ASTNode::Option{condition, block} = case {
+						for partial_condition in condition {
+							self.infer(partial_condition);
+						}
+						self.scoped_block(block);
+					}
+				}
+			},
+			ASTNode::Impl{block, ..} => {
+				for method in block {
+					self.check_statement(method);
+				}
+			},
+			ASTNode::Struct{..} | ASTNode::Use{..} => (),
 			_ => {
-				 println!("(Int)Invalid Value");
-				 std::process::exit(1);
-			 }
+				self.infer(statement);
+			}
+		}
+	}
+
+	fn param_name(&self, param: &ASTNode) -> String {
+		match param {
+			ASTNode::ID{name, ..} => name.to_string(),
+			ASTNode::Spread{value} => self.param_name(value),
+			_ => String::new()
+		}
+	}
+
+	fn scoped_block(&mut self, block: &Vec<ASTNode>) {
+		self.scopes.push(HashMap::new());
+		self.check_block(block);
+		self.scopes.pop();
+	}
+
+	fn lookup(&self, name: &str) -> Option<String> {
+		for scope in self.scopes.iter().rev() {
+			if let Some(type_name) = scope.get(name) {
+				return Some(type_name.clone());
+			}
+		}
+		None
+	}
+
+	fn annotated(&self, expression: &ASTNode) -> bool {
+		// Whether the type of an expression comes from an annotation
+		match expression {
+			ASTNode::ID{name, ..} => self.lookup(name).is_some_and(|type_name| type_name != "Any"),
+			ASTNode::FunctionCall{name, ..} => match **name {
+				ASTNode::ID{ref name, ..} => self.signature(name).is_some_and(|(_, return_type)| return_type.is_some()),
+				_ => false
+			},
+			ASTNode::UnaryOperation{operand, ..} => self.annotated(operand),
+			ASTNode::BinaryOperation{left, right, ..} => self.annotated(left) || self.annotated(right),
+			_ => false
+		}
+	}
+
+	fn compatible(&self, expected: &str, found: &str) -> bool {
+		expected == "Any" || found == "Any" || expected == found
+	}
+
+	fn error(&mut self, message: String) {
+		self.errors.push(format!("TypeError: {message} (in `{}`)", self.context));
+	}
+
+	fn infer(&mut self, expression: &ASTNode) -> Option<String> {
+		// Returns the static type of an expression, None when it is only known at runtime
+		let type_name = match expression {
+			ASTNode::Integer{..} => "Int",
+			ASTNode::Float{..} => "Float",
+			ASTNode::Str{..} => "Str",
+			ASTNode::Bool{..} => "Bool",
+			ASTNode::None => "None",
+			ASTNode::ExpressionList{list} => {
+				for item in list {
+					self.infer(item);
+				}
+				"Vector"
+			},
+			ASTNode::ID{name, ..} => return self.lookup(name),
+			ASTNode::Keyword{value, ..} => return self.infer(value),
+			ASTNode::Lambda{parameters, return_type, block} => {
+				self.scopes.push(HashMap::new());
+				self.return_types.push(return_type.clone());
+
+				for param in parameters.0.iter().flatten() {
+					let name = match param {
+						ASTNode::Var{name, ..} => self.param_name(name),
+						_ => self.param_name(param)
+					};
+					self.scopes.last_mut().unwrap().insert(name, String::from("Any"));
+				}
+				self.check_block(block);
+
+				self.return_types.pop();
+				self.scopes.pop();
+				"Function"
+			},
+			ASTNode::Spread{value} => {
+				self.infer(value);
+				return None;
+			},
+			ASTNode::If{..} | ASTNode::Loop{..} | ASTNode::Try{..} => {
+				self.check_statement(expression);
+				return None;
+			},
+			ASTNode::Flow{result: Some(result), ..} => {
+				self.infer(result);
+				return None;
+			},
+			ASTNode::Index{object, index} => {
+				self.infer(object);
+				self.infer(index);
+				return None;
+			},
+			ASTNode::UnaryOperation{operand, operator} => {
+				let found = self.infer(operand);
+				let annotated = self.annotated(operand);
+
+				return match (operator.as_str(), found) {
+					("!", _) => Some(String::from("Bool")),
+					(_, None) => None,
+					(_, Some(found)) if found == "Any" => None,
+					("-" | "+", Some(found)) if found == "Int" || found == "Float" => Some(found),
+					("~", Some(found)) if found == "Int" => Some(found),
+					("++" | "--", Some(found)) if found == "Int" || found == "Float" => Some(found),
+					(_, Some(_)) if !annotated => None,
+					(_, Some(found)) => {
+						self.error(format!("Cannot apply unary operator `{operator}` to type {found}"));
+						None
+					}
+				};
+			},
+			ASTNode::BinaryOperation{left, operation, right} => {
+				let annotated = self.annotated(left) || self.annotated(right);
+				let left = self.infer(left);
+				let right = self.infer(right);
+
+				if ["&&", "||", ">", ">=", "<", "<=", "==", "!="].contains(&operation.as_str()) &&
+					(left.is_none() || right.is_none()) {
+					return Some(String::from("Bool"));
+				}
+				let (Some(left), Some(right)) = (left, right) else { return None };
+
+				if left == "Any" || right == "Any" {
+					return None;
+				}
+				let result = self.binary_type(operation, &left, &right);
+
+				// Literals alone are left to the runtime -> `1 + "a"` in a branch never taken is fine
+				if result.is_none() && annotated {
+					self.error(format!("No implementation for `{left} {operation} {right}`"));
+				}
+				return result;
+			},
+			ASTNode::FunctionCall{name, args} => {
+				let arg_types: Vec<Option<String>> = args.iter().map(|arg| self.infer(arg)).collect();
+				let ASTNode::ID{ref name, ..} = **name else { return None };
+
+				return match name.as_str() {
+					"type" | "str" => Some(String::from("Str")),
+					"int" | "len" => Some(String::from("Int")),
+					"float" => Some(String::from("Float")),
+					"bool" | "is_int" | "is_float" | "is_bool" | "is_str" | "is_none" | "is_vector" => {
+						Some(String::from("Bool"))
+					},
+					"print" | "println" => Some(String::from("None")),
+					"range" | "sorted" => Some(String::from("Vector")),
+					"any" | "all" => Some(String::from("Bool")),
+					// Vector or Generator, depending on what they are given
+					"map" | "filter" | "take" | "skip" | "zip" | "enumerate" => None,
+					"reduce" | "sum" | "min" | "max" => None,
+					_ if self.structs.contains(name) => Some(name.to_string()),
+					_ => {
+						let (param_types, return_type) = self.signature(name)?.clone();
+
+						// Positions are unknown once an argument is spread
+						let spread = args.iter().position(|arg| matches!(arg, ASTNode::Spread{..}));
+
+						for (i, (arg, found)) in args.iter().zip(arg_types).enumerate() {
+							let param = match arg {
+								ASTNode::Keyword{name, ..} => param_types.iter().find(|(param, _)| param == name),
+								_ if spread.is_some_and(|spread| i >= spread) => None,
+								_ => param_types.get(i)
+							};
+							if let (Some((param, Some(expected))), Some(found)) = (param, found) {
+								if !self.compatible(expected, &found) {
+									self.error(format!(
+										"Parameter `{param}` of `{name}` expects `{expected}`, found `{found}`"
+									));
+								}
+							}
+						}
+						return_type
+					}
+				};
+			},
+			_ => return None
 		};
-		print!("{res}");
-		Ok(())
+		Some(type_name.to_string())
+	}
+
+	fn binary_type(&self, operation: &str, left: &str, right: &str) -> Option<String> {
+		// Mirrors the implementations in Executor::evaluate_binary_expression
+		let number = |t: &str| t == "Int" || t == "Float";
+
+		let result = match operation {
+			"+" | "-" | "*" | "/" | "^" if number(left) && number(right) => left,
+			"+" if left == "Str" && right == "Str" => "Str",
+			"+" if left == "Vector" => "Vector",
+			"*" if (left == "Int" && right == "Str") || (left == "Str" && right == "Int") => "Str",
+			"%" if left == right && number(left) => left,
+			">" | ">=" | "<" | "<=" | "==" | "!=" if number(left) && number(right) => "Bool",
+			">" | ">=" | "<" | "<=" | "==" | "!=" if left == right && ["Bool", "Str", "Vector"].contains(&left) => "Bool",
+			"&" | "|" | "~" if left == right && (left == "Int" || left == "Bool") => left,
+			"<<" | ">>" if left == "Int" && right == "Int" => "Int",
+			"&&" | "||" => "Bool",
+			_ => return None
+		};
+		Some(result.to_string())
 	}
 }
-	
 
-impl Executor {
-    fn new(ast: Vec<ASTNode>) -> Executor {
-        Self {
-            ast,
-            functions: vec![HashMap::new()],
-            scopes: vec![HashMap::new()],
-            current_scope: HashMap::new(),
-            structs: HashMap::new(),
-            structs_impl: HashMap::new(),
-            local_variables: vec![],
-            return_value: None,
-            break_loop: false,
-			continue_loop: false,
-        }
-    }
+struct Resolver {
+	// Pre-execution pass binding every identifier to its lexical declaration.
+	// A function sees its own locals, the blocks around it when it is nested (or a lambda), and globals.
+	// Top-level code only sees what was declared before it, function bodies see every global
+	scopes: Vec<Vec<(String, usize)>>,    // names declared in each block, with the line declaring them
+	frames: Vec<usize>,        // index in `scopes` where each top-level function starts
+	globals: Vec<(String, usize)>,        // declared so far at the top level
+	hoisted: Vec<(String, usize)>,        // every global, visible from function bodies
+	declarations: HashMap<(String, usize), usize>,    // every read (name, line) -> line of the declaration it reads
+	function_depth: usize,
+	loops: Vec<(Option<String>, bool)>,    // label of each loop around inside the current function, and if it is a `loop`
+	context: String,
+	errors: Vec<String>,
+	warnings: Vec<String>,     // unresolved names, left to the runtime so that `try` can catch them
+}
 
-    fn execute(&mut self) {
-		for statement in self.ast.clone().into_iter() {
-			self.execute_statement(statement);
+impl Resolver {
+	fn new() -> Self {
+		Self {
+			scopes: vec![],
+			frames: vec![],
+			globals: BUILTIN_FUNCTIONS.iter().map(|name| (name.to_string(), 0)).collect(),
+			hoisted: vec![],
+			declarations: HashMap::new(),
+			function_depth: 0,
+			loops: vec![],
+			context: String::from("<main>"),
+			errors: vec![],
+			warnings: vec![],
 		}
-    }
+	}
+
+	fn resolve(&mut self, ast: &Vec<ASTNode>) -> Vec<String> {
+		self.hoist(ast, true);
+		self.resolve_block(ast);
+
+		return self.errors.clone();
+	}
+
+	fn hoist(&mut self, block: &Vec<ASTNode>, top_level: bool) {
+		// Top-level functions and structs (wherever they are declared) are global
+		for statement in block {
+			match statement {
+				ASTNode::Function{name, block, ..} => {
+					if top_level {
+						self.hoisted.push((self.name_of(name), self.line_of(name)));
+						self.globals.push((self.name_of(name), self.line_of(name)));
+					}
+					self.hoist(block, false);
+				},
+				ASTNode::Struct{name, ..} => {
+					self.hoisted.push((self.name_of(name), self.line_of(name)));
+					self.globals.push((self.name_of(name), self.line_of(name)));
+				},
+				ASTNode::Var{name, ..} if top_level => self.hoisted.push((self.name_of(name), self.line_of(name))),
+				ASTNode::If{if_block, else_block, ..} => {
+					self.hoist(if_block, false);
+					self.hoist(else_block.as_ref().unwrap_or(&vec![]), false);
+				},
+				ASTNode::While{body, ..} | ASTNode::For{body, ..} | ASTNode::Loop{body, ..} => self.hoist(body, false),
+				ASTNode::Match{cases, ..} => {
+					for case in cases {
+						if let ASTNode::Option{block, ..} = case {
+							self.hoist(block, false);
+						}
+					}
+				},
+				ASTNode::Try{body, catches, finally} => {
+					self.hoist(body, false);
+					self.hoist(catches, false);
+					self.hoist(finally.as_ref().unwrap_or(&vec![]), false);
+				},
+				ASTNode::Catch{block, ..} => self.hoist(block, false),
+				_ => ()
+			}
+		}
+	}
+
+	fn hoist_locals(&mut self, block: &[ASTNode]) {
+		// Nested functions are visible in their whole block, so siblings can call each other
+		if self.scopes.is_empty() {
+			return;
+		}
+		for statement in block {
+			if let ASTNode::Function{name, ..} = statement {
+				self.declare(name);
+			}
+		}
+	}
+
+	fn name_of(&self, node: &ASTNode) -> String {
+		match node {
+			ASTNode::ID{name, ..} => name.to_string(),
+			ASTNode::Var{name, ..} | ASTNode::Spread{value: name} => self.name_of(name),
+			_ => String::new()
+		}
+	}
+
+	fn line_of(&self, node: &ASTNode) -> usize {
+		match node {
+			ASTNode::ID{line, ..} => *line,
+			ASTNode::Var{name, ..} | ASTNode::Spread{value: name} => self.line_of(name),
+			_ => 0
+		}
+	}
+
+	fn declare(&mut self, node: &ASTNode) {
+		let declaration = (self.name_of(node), self.line_of(node));
+
+		match self.scopes.last_mut() {
+			Some(scope) => scope.push(declaration),
+			None => self.globals.push(declaration)
+		}
+	}
+
+	fn declaration(&self, name: &str) -> Option<usize> {
+		// Line of the declaration `name` resolves to here, the latest one of each scope
+		let start = self.frames.last().copied().unwrap_or(0);
+		let find = |scope: &[(String, usize)]| scope.iter().rev().find(|(declared, _)| declared == name).map(|(_, line)| *line);
+
+		for scope in self.scopes[start..].iter().rev() {
+			if let Some(line) = find(scope) {
+				return Some(line);
+			}
+		}
+		find(&self.globals).or_else(|| if self.function_depth > 0 { find(&self.hoisted) } else { None })
+	}
+
+	fn is_declared(&self, name: &str) -> bool {
+		self.declaration(name).is_some()
+	}
+
+	fn warn(&mut self, message: String) {
+		self.warnings.push(format!("Warning: {message} (in `{}`), reaching it raises a NameError", self.context));
+	}
+
+	fn resolve_block(&mut self, block: &Vec<ASTNode>) {
+		self.hoist_locals(block);
+
+		for statement in block {
+			self.resolve_statement(statement);
+		}
+	}
+
+	fn scoped_block(&mut self, block: &Vec<ASTNode>) {
+		self.scopes.push(vec![]);
+		self.resolve_block(block);
+		self.scopes.pop();
+	}
+
+	fn resolve_statement(&mut self, statement: &ASTNode) {
+		match statement {
+			ASTNode::Var{name, value, ..} => {
+				// The value is resolved before the name exists -> let x = x + 1; uses the outer x
+				if let Some(value) = value {
+					self.resolve_expression(value);
+				}
+				self.declare(name);
+			},
+			ASTNode::Assign{name, value} => {
+				self.resolve_expression(value);
+				let name = self.name_of(name);
+
+				if !self.is_declared(&name) {
+					self.warn(format!("Cannot assign to `{name}`, it was never declared"));
+				}
+			},
+			ASTNode::Function{name, parameters, block, ..} => {
+				let name = self.name_of(name);
+				let context = std::mem::replace(&mut self.context, format!("fn {name}"));
+
+				if self.scopes.is_empty() {
+					self.frames.push(self.scopes.len());
+					self.resolve_function(parameters, block);
+					self.frames.pop();
+				} else {
+					// Nested functions are local, declared with their block, and see the blocks around them
+					self.resolve_function(parameters, block);
+				}
+				self.context = context;
+			},
+			ASTNode::Return{list} => {
+				for expression in list {
+					self.resolve_expression(expression);
+				}
+			},
+			ASTNode::Yield{value} => self.resolve_expression(value),
+			ASTNode::If{condition, if_block, else_block} => {
+				self.resolve_expression(condition);
+				self.scoped_block(if_block);
+
+				if let Some(else_block) = else_block {
+					self.scoped_block(else_block);
+				}
+			},
+			ASTNode::While{condition, body, label, ..} => {
+				self.resolve_expression(condition);
+				self.loops.push((label.clone(), false));
+				self.scoped_block(body);
+				self.loops.pop();
+			},
+			ASTNode::Loop{body, label} => {
+				self.loops.push((label.clone(), true));
+				self.scoped_block(body);
+				self.loops.pop();
+			},
+			ASTNode::Try{body, catches, finally} => {
+				self.scoped_block(body);
+
+				for clause in catches {
+					if let ASTNode::Catch{name, block, ..} = clause {
+						self.scopes.push(name.iter().map(|name| (self.name_of(name), self.line_of(name))).collect());
+						self.resolve_block(block);
+						self.scopes.pop();
+					}
+				}
+				if let Some(finally) = finally {
+					self.scoped_block(finally);
+				}
+			},
+			ASTNode::Throw{value, ..} => self.resolve_expression(value),
+			ASTNode::For{loop_var, object, body, label} => {
+				self.resolve_expression(object);
+				self.loops.push((label.clone(), false));
+				self.scopes.push(vec![(self.name_of(loop_var), self.line_of(loop_var))]);
+				self.resolve_block(body);
+				self.scopes.pop();
+				self.loops.pop();
+			},
+			ASTNode::Match{option, cases} => {
+				self.resolve_expression(option);
+
+				for case in cases {
+					if let ASTNode::Option{condition, block} = case {
+						for partial_condition in condition {
+							self.resolve_expression(partial_condition);
+						}
+						self.scoped_block(block);
+					}
+				}
+			},
+			ASTNode::Impl{block, ..} => {
+				for method in block {
+					self.resolve_statement(method);
+				}
+			},
+			ASTNode::Struct{..} | ASTNode::Use{..} => (),
+			_ => self.resolve_expression(statement)
+		}
+	}
+
+	fn resolve_function(&mut self, parameters: &(Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), block: &Vec<ASTNode>) {
+		// Parameters are declared in order, so defaults can use the ones before them.
+		// `break` and `continue` cannot reach the loops around a function
+		self.scopes.push(vec![]);
+		self.function_depth += 1;
+		let loops = std::mem::take(&mut self.loops);
+
+		for param in parameters.0.iter().flatten().chain(parameters.1.iter().flatten()) {
+			if let ASTNode::Var{value: Some(value), ..} = param {
+				self.resolve_expression(value);
+			}
+			self.declare(param);
+		}
+		self.resolve_block(block);
+
+		self.loops = loops;
+		self.function_depth -= 1;
+		self.scopes.pop();
+	}
+
+	fn resolve_expression(&mut self, expression: &ASTNode) {
+		match expression {
+			ASTNode::ID{name, line} => {
+				match self.declaration(name) {
+					Some(declared) => { self.declarations.insert((name.to_string(), *line), declared); },
+					None => self.warn(format!("`{name}` is not defined"))
+				}
+			},
+			// Only the object is a variable -> car.make
+			ASTNode::PropertyAccess{object, ..} => self.resolve_expression(object),
+			ASTNode::Index{object, index} => {
+				self.resolve_expression(object);
+				self.resolve_expression(index);
+			},
+			ASTNode::UnaryOperation{operand, ..} => self.resolve_expression(operand),
+			ASTNode::BinaryOperation{left, right, ..} => {
+				self.resolve_expression(left);
+				self.resolve_expression(right);
+			},
+			ASTNode::ExpressionList{list} => {
+				for item in list {
+					self.resolve_expression(item);
+				}
+			},
+			ASTNode::FunctionCall{name, args} => {
+				self.resolve_expression(name);
+
+				for arg in args {
+					self.resolve_expression(arg);
+				}
+			},
+			ASTNode::Keyword{value, ..} | ASTNode::Spread{value} => self.resolve_expression(value),
+			ASTNode::Lambda{parameters, block, ..} => self.resolve_function(parameters, block),
+			ASTNode::If{..} | ASTNode::Loop{..} | ASTNode::Try{..} => self.resolve_statement(expression),
+			ASTNode::Flow{value, label, result} => {
+				if let Some(result) = result {
+					self.resolve_expression(result);
+				}
+				let target = match label {
+					None => self.loops.last(),
+					Some(_) => self.loops.iter().rev().find(|(name, _)| name == label)
+				};
+				match target {
+					None if label.is_some() => {
+						self.errors.push(format!("SyntaxError: No loop labeled '{} around `{value}` (in `{}`)", label.clone().unwrap(), self.context));
+					},
+					None => self.errors.push(format!("SyntaxError: `{value}` outside of a loop (in `{}`)", self.context)),
+					Some((_, false)) if result.is_some() => {
+						self.errors.push(format!("SyntaxError: Only `loop` can be left with a value (in `{}`)", self.context));
+					},
+					_ => ()
+				}
+			},
+			_ => ()
+		}
+	}
+}
+
+struct Purity {
+	// Pre-execution pass finding the `let` initializers with side effects.
+	// Deferring them would move the effects to wherever the binding is first read,
+	// so they are marked eager. `lazy let` and `eager let` are left as written
+	functions: HashMap<String, Option<String>>,    // functions, methods and lambda bindings -> why they are impure
+	structs: Vec<String>,
+	reassigned: Vec<String>,       // names assigned or incremented anywhere, a deferred call could read a later value
+	warnings: Vec<String>,
+	in_try: bool,                  // marking the body of a `try`, its errors must happen inside it
+}
+
+impl Purity {
+	fn new() -> Self {
+		Self {
+			functions: HashMap::new(),
+			structs: vec![],
+			reassigned: vec![],
+			warnings: vec![],
+			in_try: false,
+		}
+	}
+
+	fn mark(&mut self, mut ast: Vec<ASTNode>) -> Vec<ASTNode> {
+		let mut definitions: Vec<(String, (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), Vec<ASTNode>)> = vec![];
+		self.collect(&ast, &mut definitions);
+		ast.iter().for_each(|statement| assigned_names(statement, &mut self.reassigned));
+
+		for (name, ..) in &definitions {
+			self.functions.insert(name.to_string(), None);
+		}
+		// Everything starts pure, effects spread to callers until nothing changes.
+		// Functions sharing a name (methods, nested functions) are impure if any of them is
+		loop {
+			let mut changed = false;
+
+			for (name, parameters, block) in &definitions {
+				if self.functions[name].is_some() {
+					continue;
+				}
+				if let Some(reason) = self.function_effect(parameters, block) {
+					self.functions.insert(name.to_string(), Some(reason));
+					changed = true;
+				}
+			}
+			if !changed {
+				break;
+			}
+		}
+		self.mark_block(&mut ast);
+		ast
+	}
+
+	fn collect(&mut self, block: &Vec<ASTNode>, definitions: &mut Vec<(String, (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), Vec<ASTNode>)>) {
+		for statement in block {
+			match statement {
+				ASTNode::Function{name, parameters, block, ..} => {
+					definitions.push((self.name_of(name), parameters.clone(), block.clone()));
+					self.collect(block, definitions);
+				},
+				ASTNode::Var{name, value: Some(value), ..} => {
+					if let ASTNode::Lambda{parameters, block, ..} = &**value {
+						definitions.push((self.name_of(name), parameters.clone(), block.clone()));
+						self.collect(block, definitions);
+					}
+				},
+				ASTNode::Struct{name, ..} => self.structs.push(self.name_of(name)),
+				ASTNode::Impl{block, ..} => self.collect(block, definitions),
+				ASTNode::If{if_block, else_block, ..} => {
+					self.collect(if_block, definitions);
+					self.collect(else_block.as_ref().unwrap_or(&vec![]), definitions);
+				},
+				ASTNode::While{body, ..} | ASTNode::For{body, ..} | ASTNode::Loop{body, ..} => self.collect(body, definitions),
+				ASTNode::Match{cases, ..} => {
+					for case in cases {
+						if let ASTNode::Option{block, ..} = case {
+							self.collect(block, definitions);
+						}
+					}
+				},
+				ASTNode::Try{body, catches, finally} => {
+					self.collect(body, definitions);
+					self.collect(catches, definitions);
+					self.collect(finally.as_ref().unwrap_or(&vec![]), definitions);
+				},
+				ASTNode::Catch{block, ..} => self.collect(block, definitions),
+				_ => ()
+			}
+		}
+	}
+
+	fn name_of(&self, node: &ASTNode) -> String {
+		match node {
+			ASTNode::ID{name, ..} => name.to_string(),
+			ASTNode::Var{name, ..} | ASTNode::Spread{value: name} => self.name_of(name),
+			_ => String::new()
+		}
+	}
+
+	fn mark_block(&mut self, block: &mut [ASTNode]) {
+		for statement in block.iter_mut() {
+			match statement {
+				ASTNode::Var{name, value: Some(value), eager, ..} => {
+					self.mark_lambdas(Rc::make_mut(value));
+
+					// Creating a closure has no effect, it is never deferred anyway
+					if eager.is_some() || matches!(**value, ASTNode::Lambda{..}) {
+						continue;
+					}
+					let effect = self.expression_effect(value, &vec![])
+						.or_else(|| self.jump_effect(value, &mut vec![]))
+						.or_else(|| self.in_try.then(|| String::from("is declared inside `try`")));
+
+					if let Some(reason) = effect {
+						let line = match **name {
+							ASTNode::ID{line, ..} => line,
+							_ => 0
+						};
+						self.warnings.push(format!("Warning: `{}` (line {line}) is evaluated eagerly, it {reason}", self.name_of(name)));
+						*eager = Some(true);
+					}
+				},
+				ASTNode::Function{block, ..} | ASTNode::Impl{block, ..} => {
+					// Runs wherever it is called
+					let in_try = std::mem::replace(&mut self.in_try, false);
+					self.mark_block(block);
+					self.in_try = in_try;
+				},
+				ASTNode::If{condition, if_block, else_block} => {
+					self.mark_lambdas(Rc::make_mut(condition));
+					self.mark_block(if_block);
+
+					if let Some(else_block) = else_block {
+						self.mark_block(else_block);
+					}
+				},
+				ASTNode::While{condition: object, body, ..} | ASTNode::For{object, body, ..} => {
+					self.mark_lambdas(Rc::make_mut(object));
+					self.mark_block(body);
+				},
+				ASTNode::Loop{body, ..} => self.mark_block(body),
+				ASTNode::Match{option, cases} => {
+					self.mark_lambdas(Rc::make_mut(option));
+
+					for case in cases.iter_mut() {
+						if let ASTNode::Option{block, ..} = case {
+							self.mark_block(block);
+						}
+					}
+				},
+				ASTNode::Try{body, catches, finally} => {
+					// A deferred error would escape the `try`, or never happen
+					let in_try = std::mem::replace(&mut self.in_try, true);
+					self.mark_block(body);
+					self.in_try = in_try;
+
+					for clause in catches.iter_mut() {
+						if let ASTNode::Catch{block, ..} = clause {
+							self.mark_block(block);
+						}
+					}
+					if let Some(finally) = finally {
+						self.mark_block(finally);
+					}
+				},
+				statement => self.mark_lambdas(statement)
+			}
+		}
+	}
+
+	fn mark_lambdas(&mut self, expression: &mut ASTNode) {
+		// Marks the bodies of the lambdas written anywhere in an expression, e.g passed as arguments
+		match expression {
+			ASTNode::Lambda{block, ..} => {
+				// Runs wherever it is called
+				let in_try = std::mem::replace(&mut self.in_try, false);
+				self.mark_block(block);
+				self.in_try = in_try;
+			},
+			ASTNode::PropertyAccess{object: value, ..} | ASTNode::UnaryOperation{operand: value, ..} | ASTNode::Assign{value, ..}
+			| ASTNode::Keyword{value, ..} | ASTNode::Spread{value} | ASTNode::Yield{value} | ASTNode::Throw{value, ..}
+			| ASTNode::Flow{result: Some(value), ..} => self.mark_lambdas(Rc::make_mut(value)),
+			ASTNode::Index{object: left, index: right} | ASTNode::BinaryOperation{left, right, ..} => {
+				self.mark_lambdas(Rc::make_mut(left));
+				self.mark_lambdas(Rc::make_mut(right));
+			},
+			ASTNode::FunctionCall{name, args} => {
+				self.mark_lambdas(Rc::make_mut(name));
+				args.iter_mut().for_each(|arg| self.mark_lambdas(arg));
+			},
+			ASTNode::ExpressionList{list} | ASTNode::Return{list} => list.iter_mut().for_each(|item| self.mark_lambdas(item)),
+			ASTNode::If{..} | ASTNode::Loop{..} | ASTNode::Match{..} | ASTNode::Try{..} => self.mark_block(std::slice::from_mut(expression)),
+			_ => ()
+		}
+	}
+
+	fn function_effect(&self, parameters: &(Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), block: &Vec<ASTNode>) -> Option<String> {
+		// Changing its own locals is fine, anything else the caller could notice is an effect
+		let mut locals: Vec<String> = vec![];
+
+		for param in parameters.0.iter().flatten().chain(parameters.1.iter().flatten()) {
+			if let ASTNode::Var{value: Some(value), ..} = param {
+				if let Some(reason) = self.expression_effect(value, &locals) {
+					return Some(reason);
+				}
+			}
+			locals.push(self.name_of(param));
+		}
+		declared_names(block, &mut locals);
+
+		self.block_effect(block, &locals).or_else(|| {
+			// The thunk only captures what the initializer mentions, the function reads the live binding
+			let mut names: Vec<String> = vec![];
+			block.iter().for_each(|statement| referenced_names(statement, &mut names));
+			names.into_iter()
+				.find(|name| !locals.contains(name) && self.reassigned.contains(name))
+				.map(|name| format!("reads `{name}`, which is reassigned"))
+		})
+	}
+
+	fn block_effect(&self, block: &[ASTNode], locals: &Vec<String>) -> Option<String> {
+		block.iter().find_map(|statement| self.statement_effect(statement, locals))
+	}
+
+	fn statement_effect(&self, statement: &ASTNode, locals: &Vec<String>) -> Option<String> {
+		match statement {
+			ASTNode::Var{value, ..} => value.as_ref().and_then(|value| self.expression_effect(value, locals)),
+			ASTNode::Assign{name, value} => {
+				let name = self.name_of(name);
+
+				if !locals.contains(&name) {
+					return Some(format!("assigns to `{name}`"));
+				}
+				self.expression_effect(value, locals)
+			},
+			// A nested function only has effects when it is called
+			ASTNode::Function{..} | ASTNode::Struct{..} | ASTNode::Impl{..} | ASTNode::Use{..} => None,
+			ASTNode::Return{list} => list.iter().find_map(|expression| self.expression_effect(expression, locals)),
+			ASTNode::Yield{value} => self.expression_effect(value, locals),
+			ASTNode::If{condition, if_block, else_block} => {
+				self.expression_effect(condition, locals)
+					.or_else(|| self.block_effect(if_block, locals))
+					.or_else(|| self.block_effect(else_block.as_ref().unwrap_or(&vec![]), locals))
+			},
+			ASTNode::While{condition, body, ..} => {
+				self.expression_effect(condition, locals).or_else(|| self.block_effect(body, locals))
+			},
+			ASTNode::For{object, body, ..} => {
+				self.expression_effect(object, locals).or_else(|| self.block_effect(body, locals))
+			},
+			ASTNode::Loop{body, ..} => self.block_effect(body, locals),
+			ASTNode::Try{body, catches, finally} => {
+				self.block_effect(body, locals)
+					.or_else(|| self.block_effect(catches, locals))
+					.or_else(|| self.block_effect(finally.as_ref().unwrap_or(&vec![]), locals))
+			},
+			ASTNode::Catch{block, ..} => self.block_effect(block, locals),
+			// Deferred, the error would be raised where the binding is read
+			ASTNode::Throw{..} => Some(String::from("uses `throw`")),
+			ASTNode::Match{option, cases} => {
+				self.expression_effect(option, locals).or_else(|| cases.iter().find_map(|case| match case {
+					ASTNode::Option{condition, block} => {
+						condition.iter().find_map(|condition| self.expression_effect(condition, locals))
+							.or_else(|| self.block_effect(block, locals))
+					},
+					_ => None
+				}))
+			},
+			_ => self.expression_effect(statement, locals)
+		}
+	}
+
+	fn expression_effect(&self, expression: &ASTNode, locals: &Vec<String>) -> Option<String> {
+		match expression {
+			ASTNode::FunctionCall{name, args} => {
+				self.call_effect(name, locals).or_else(|| args.iter().find_map(|arg| self.expression_effect(arg, locals)))
+			},
+			ASTNode::UnaryOperation{operand, operator} if operator == "++" || operator == "--" => {
+				let name = self.name_of(operand);
+
+				match locals.contains(&name) {
+					true => None,
+					false => Some(format!("changes `{name}`"))
+				}
+			},
+			ASTNode::UnaryOperation{operand, ..} => self.expression_effect(operand, locals),
+			ASTNode::BinaryOperation{left, right, ..} | ASTNode::Index{object: left, index: right} => {
+				self.expression_effect(left, locals).or_else(|| self.expression_effect(right, locals))
+			},
+			ASTNode::PropertyAccess{object, ..} => self.expression_effect(object, locals),
+			ASTNode::ExpressionList{list} => list.iter().find_map(|item| self.expression_effect(item, locals)),
+			ASTNode::Keyword{value, ..} | ASTNode::Spread{value} => self.expression_effect(value, locals),
+			// It may be called right away -> map(items, |x| println(x))
+			ASTNode::Lambda{parameters, block, ..} => self.function_effect(parameters, block),
+			ASTNode::If{..} | ASTNode::Loop{..} | ASTNode::Try{..} => self.statement_effect(expression, locals),
+			ASTNode::Flow{result: Some(result), ..} => self.expression_effect(result, locals),
+			_ => None
+		}
+	}
+
+	fn jump_effect(&self, node: &ASTNode, loops: &mut Vec<Option<String>>) -> Option<String> {
+		// A `break` / `continue` leaving the initializer has to run where the `let` is.
+		// Loops inside the initializer catch their own
+		if let ASTNode::Flow{value, label, result} = node {
+			let caught = match label {
+				None => !loops.is_empty(),
+				Some(_) => loops.contains(label)
+			};
+			if !caught {
+				return Some(format!("uses `{value}`"));
+			}
+			return result.as_ref().and_then(|result| self.jump_effect(result, loops));
+		}
+		let mut visit = |node: &ASTNode| self.jump_effect(node, loops);
+
+		match node {
+			ASTNode::While{body, label, ..} | ASTNode::For{body, label, ..} | ASTNode::Loop{body, label} => {
+				loops.push(label.clone());
+				let effect = body.iter().find_map(|statement| self.jump_effect(statement, loops));
+				loops.pop();
+				effect
+			},
+			ASTNode::If{condition, if_block, else_block} => {
+				visit(condition).or_else(|| if_block.iter().chain(else_block.iter().flatten()).find_map(visit))
+			},
+			ASTNode::Match{option, cases} => visit(option).or_else(|| cases.iter().find_map(visit)),
+			ASTNode::Option{block, ..} | ASTNode::Catch{block, ..} => block.iter().find_map(visit),
+			ASTNode::Try{body, catches, finally} => {
+				body.iter().chain(catches.iter()).chain(finally.iter().flatten()).find_map(visit)
+			},
+			ASTNode::BinaryOperation{left, right, ..} => visit(left).or_else(|| visit(right)),
+			ASTNode::UnaryOperation{operand: value, ..} | ASTNode::Var{value: Some(value), ..} |
+			ASTNode::Assign{value, ..} | ASTNode::Keyword{value, ..} | ASTNode::Spread{value} => visit(value),
+			ASTNode::FunctionCall{args: list, ..} | ASTNode::ExpressionList{list} | ASTNode::Return{list} => list.iter().find_map(visit),
+			_ => None
+		}
+	}
+
+	fn call_effect(&self, callee: &ASTNode, locals: &Vec<String>) -> Option<String> {
+		let name = match callee {
+			ASTNode::ID{name, ..} => name.to_string(),
+			ASTNode::PropertyAccess{object, property} => {
+				if let Some(reason) = self.expression_effect(object, locals) {
+					return Some(reason);
+				}
+				self.name_of(property)
+			},
+			_ => return Some(String::from("calls a function value, which may have side effects"))
+		};
+		if name == "print" || name == "println" {
+			return Some(format!("calls `{name}`"));
+		}
+		if BUILTIN_FUNCTIONS.contains(&name.as_str()) || self.structs.contains(&name) {
+			return None;
+		}
+		match self.functions.get(&name) {
+			Some(Some(reason)) => Some(format!("calls `{name}`, which {reason}")),
+			Some(None) => None,
+			// A parameter or a variable holding a function, it could do anything
+			None => Some(format!("calls `{name}`, which may have side effects"))
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+enum LazyResult {
+	Null, //No return used int
+	Int(i32),
+	Float(f64),
+	Str(String),
+	Bool(bool),
+	List(Vec<Value>),
+	Struct(Rc<RefCell<StructInstance>>),
+	Function(Rc<FunctionValue>),
+	Generator(Rc<Generator>),
+	None,      // Used in Mar
+	// A deferred expression with the bindings it referenced when it was declared.
+	// memo and environment are shared by every copy of the thunk, the environment is emptied once forced.
+	// depth is the longest chain of unforced thunks it leans on, declared the line of its `let`
+	Expression{expr: Rc<ASTNode>, environment: Rc<RefCell<HashMap<String, Option<LazyResult>>>>, memo: Rc<RefCell<Option<Value>>>, depth: usize, declared: usize},
+	// A binding captured by a closure or local function, the scope and the closure hold the same cell.
+	// Only ever stored in a scope, reads and assignments go through it
+	Shared(Rc<RefCell<Option<LazyResult>>>),
+}
+
+struct Executor {
+    ast: Vec<ASTNode>,
+    current_scope: HashMap<String, Option<LazyResult>>,
+    scopes: Vec<HashMap<String, Option<LazyResult>>>,
+	frames: Vec<usize>,             // index in `scopes` where each running call starts
+    functions: Vec<HashMap<String, ((Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), Vec<ASTNode>)>>,
+	local_variables: Vec<String>,
+    return_value: Option<Value>,
+    structs: HashMap<String, Vec<ASTNode>>,
+    structs_impl: HashMap<String, Vec<ASTNode>>,
+	signal: Option<Signal>,         // a `break` / `continue` looking for its loop
+	call_depth: usize,              // nested function calls and forced thunks
+	max_call_depth: usize,
+	tail_call: Option<(Call, Vec<ASTNode>)>,
+	eager: bool,                    // evaluate `let` initializers right away
+	consumers: Vec<Consumer>,       // running generators, innermost last
+	lazy_stats: Option<HashMap<(String, usize), LazyStat>>,    // only kept with --lazy-stats, by name and declaration line
+	declarations: HashMap<(String, usize), usize>,    // from the Resolver, the declaration line each read refers to
+	forcing: Vec<(String, usize, usize)>,             // lazy bindings being evaluated -> (name, declared at, read at)
+	line: usize,                    // last source line reached, the location of runtime errors
+	trying: usize,                  // `try` blocks running, errors unwind instead of ending the program
+	try_frames: Vec<usize>,         // self.frames.len() of every running `try` statement
+	thrown: Option<Value>,          // the struct a `throw` is unwinding with
+}
+
+// A lazy binding leaning on a longer chain of unforced thunks is forced when declared
+const THUNK_DEPTH_LIMIT: usize = 32;
+
+const BUILTIN_FUNCTIONS: [&str; 28] = [
+	"print",
+	"println",
+	"type",
+	"int",
+	"float",
+	"str",
+	"bool",
+	"len",
+	"is_int",
+	"is_float",
+	"is_bool",
+	"is_str",
+	"is_none",
+	"is_vector",
+	"range",
+	"map",
+	"filter",
+	"reduce",
+	"any",
+	"all",
+	"sum",
+	"min",
+	"max",
+	"sorted",
+	"zip",
+	"enumerate",
+	"take",
+	"skip",
+];
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+struct Value {
+	int_value: Option<i32>,
+	float_value: Option<f64>,
+	bool_value: Option<bool>,
+	string_value: Option<String>,
+	list_value: Option<Vec<Value>>,
+	struct_value: Option<Rc<RefCell<StructInstance>>>,
+	func_value: Option<Rc<FunctionValue>>,
+	generator_value: Option<Rc<Generator>>,
+	value_type: u8
+	/*
+	 * 0   - ----- - int
+	 * 1   - ----- - float
+ 	 * 2   - ----- - bool
+ 	 * 3   - ----- - string
+ 	 * 4   - ----- - None
+ 	 * 5   - ----- - list
+	 * 6   - ----- - struct instance
+	 * 7   - ----- - function
+	 * 8   - ----- - generator
+ 	 * 127 - ----- - Undefined
+ 	 */
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+struct StructInstance {
+	name: String,
+	fields: Vec<(String, Value)>
+}
+
+#[derive(Debug)]
+struct FunctionValue {
+	name: String,
+	parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>),
+	block: Vec<ASTNode>,
+	environment: HashMap<String, Option<LazyResult>>,    // captured non-global bindings
+}
+
+// Functions are only equal to themselves
+impl PartialEq for FunctionValue {
+	fn eq(&self, other: &Self) -> bool {
+		std::ptr::eq(self, other)
+	}
+}
+
+impl PartialOrd for FunctionValue {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		if self == other { Some(std::cmp::Ordering::Equal) } else { None }
+	}
+}
+
+// A resolved callee, ready to be invoked with arguments
+#[derive(Debug, Clone)]
+struct Call {
+	name: String,
+	parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>),
+	block: Vec<ASTNode>,
+	receiver: Option<LazyResult>,                        // bound to `self` for methods
+	environment: HashMap<String, Option<LazyResult>>,
+}
+
+// A sequence produced on demand. Nothing runs until it is consumed,
+// the combinators wrap their source instead of building a Vector
+#[derive(Debug)]
+enum Generator {
+	Call{ call: Call, args: Vec<ASTNode> },      // a function using `yield`, arguments already evaluated
+	Take{ source: Rc<Generator>, count: usize },
+	Skip{ source: Rc<Generator>, count: usize },
+	Map{ source: Rc<Generator>, function: LazyResult },
+	Filter{ source: Rc<Generator>, function: LazyResult },
+	Enumerate{ source: Rc<Generator>, start: i32 },
+	Zip{ source: Rc<Generator>, args: Vec<LazyResult> },      // `source` is the first generator in args
+}
+
+impl Generator {
+	fn name(&self) -> String {
+		match self {
+			Generator::Call{call, ..} => call.name.clone(),
+			Generator::Take{source, ..} | Generator::Skip{source, ..} |
+			Generator::Map{source, ..} | Generator::Filter{source, ..} |
+			Generator::Enumerate{source, ..} | Generator::Zip{source, ..} => source.name()
+		}
+	}
+}
+
+// Generators are only equal to themselves
+impl PartialEq for Generator {
+	fn eq(&self, other: &Self) -> bool {
+		std::ptr::eq(self, other)
+	}
+}
+
+impl PartialOrd for Generator {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		if self == other { Some(std::cmp::Ordering::Equal) } else { None }
+	}
+}
+
+// Where yielded values go, combinators first
+enum Sink {
+	Loop{ loop_var: String, body: Vec<ASTNode>, label: Option<String> },
+	Collect(Vec<Value>),
+	Take{ remaining: usize, then: Box<Sink> },
+	Skip{ remaining: usize, then: Box<Sink> },
+	Map{ function: LazyResult, then: Box<Sink> },
+	Filter{ function: LazyResult, then: Box<Sink> },
+	Enumerate{ index: i32, then: Box<Sink> },
+	Zip{ columns: Vec<Option<Vec<Value>>>, index: usize, then: Box<Sink> },      // None is the column of the driven generator
+	Any{ want: bool, function: Option<LazyResult>, found: bool },      // any, and all with want = false
+}
+
+// `break` and `continue` leave every block up to the loop they target,
+// the innermost one when no label is given. A `break` leaving a `loop` may carry its value
+#[derive(Debug, Clone)]
+enum Signal {
+	Break(Option<String>, Option<Value>),
+	Continue(Option<String>),
+}
+
+// A runtime error on its way to the `try` catching it, unwound as a panic payload.
+// A thrown struct is kept in Executor.thrown, a Value cannot cross the unwind
+#[derive(Debug)]
+struct MarError {
+	kind: String,                  // IndexError, TypeError ... or the name of a thrown struct
+	message: String,
+	line: usize,
+}
+
+impl Display for MarError {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		match self.message.is_empty() {
+			true => write!(f, "{}", self.kind)?,
+			false => write!(f, "{}: {}", self.kind, self.message)?
+		}
+		if self.line > 0 {
+			write!(f, " (line {})", self.line)?;
+		}
+		Ok(())
+	}
+}
+
+// A running generator and the state of the code consuming it
+struct Consumer {
+	frames: usize,                 // self.frames.len() when the generator started
+	scopes: usize,                 // self.scopes.len() when the generator started
+	trying: usize,                 // `try` blocks around the consumer, a `try` in the generator must not catch its errors
+	sink: Option<Sink>,
+	returned: Option<Value>,       // `rn` inside a consuming for loop, held until the generator stops
+	signal: Option<Signal>,        // likewise a `break` / `continue` for a loop around the consuming one
+}
+
+// --lazy-stats counters for every binding declared by one `let`
+#[derive(Debug, Default)]
+struct LazyStat {
+	order: usize,                  // first declaration, the report keeps source order
+	declared: usize,               // every `let`, folded and eager ones included
+	deferred: usize,               // declarations that became a thunk
+	forced: usize,                 // thunks that were evaluated
+	reads: usize,
+	time: Duration,                // spent forcing, nested thunks included
+}
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+impl Display for Value {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+		let res = match self.value_type {
+			0 => format!("{}", self.int_value.unwrap()),
+			1 => format!("{}", self.float_value.unwrap()),
+			2 => format!("{}", self.bool_value.unwrap()),
+			3 => format!("{}", self.string_value.clone().unwrap()),
+			4 => format!("None"),
+			5 => {
+				let items: Vec<String> = self.list_value.as_ref().unwrap()
+					.iter()
+					.map(|item| match item.value_type {
+						3 => format!("{:?}", item.string_value.as_ref().unwrap()),
+						_ => item.to_string()
+					})
+					.collect();
+				format!("[{}]", items.join(", "))
+			},
+			6 => {
+				let instance = self.struct_value.as_ref().unwrap().borrow();
+				let fields: Vec<String> = instance.fields
+					.iter()
+					.map(|(name, value)| match value.value_type {
+						3 => format!("{name}: {:?}", value.string_value.as_ref().unwrap()),
+						_ => format!("{name}: {value}")
+					})
+					.collect();
+				format!("{} {{ {} }}", instance.name, fields.join(", "))
+			},
+			7 => format!("<fn {}>", self.func_value.as_ref().unwrap().name),
+			8 => format!("<generator {}>", self.generator_value.as_ref().unwrap().name()),
+			127 => {
+				 println!("(Int)Undefined Value Type");
+				 std::process::exit(1);
+			 },
+			_ => {
+				 println!("(Int)Invalid Value");
+				 std::process::exit(1);
+			 }
+		};
+		write!(f, "{res}")
+	}
+}
+	
+
+impl Executor {
+    fn new(ast: Vec<ASTNode>) -> Executor {
+        Self {
+            ast,
+            functions: vec![HashMap::new()],
+            scopes: vec![HashMap::new()],
+            frames: vec![],
+            current_scope: HashMap::new(),
+            structs: HashMap::new(),
+            structs_impl: HashMap::new(),
+            local_variables: vec![],
+            return_value: None,
+			signal: None,
+			call_depth: 0,
+			max_call_depth: 1000,
+			tail_call: None,
+			eager: false,
+			consumers: vec![],
+			lazy_stats: None,
+			declarations: HashMap::new(),
+			forcing: vec![],
+			line: 0,
+			trying: 0,
+			try_frames: vec![],
+			thrown: None,
+        }
+    }
+
+    fn execute(&mut self) {
+		for statement in self.ast.clone().into_iter() {
+			self.execute_statement(statement);
+		}
+    }
+
+    fn execute_statement(&mut self, statement: ASTNode) -> LazyResult {
+		match statement {
+			ASTNode::Var{name, value, eager, ..} => {
+				let eager = eager.unwrap_or(self.eager);
+				return self.var_declaration(&name, value, eager);
+			},
+			ASTNode::Assign{name, value} => {
+				return self.assignment(&name, (*value).clone());
+			},
+			ASTNode::Function{name, parameters, block, ..} => {
+				return self.func_declaration(name, parameters, block);
+			},
+			ASTNode::Return{ list } => {
+				return self.rn_statement(list);
+			},
+			ASTNode::Yield{ value } => {
+				return self.yield_statement((*value).clone());
+			},
+			ASTNode::If{condition, if_block, else_block} => {
+				return self.if_execution((*condition).clone(), if_block, else_block);
+			},
+			ASTNode::Match{option, cases} => {
+				return self.match_execution((*option).clone(), cases);
+			},
+			ASTNode::While{condition, body, label, body_first} => {
+				return self.while_execution((*condition).clone(), body, label, body_first);
+			},
+			ASTNode::Loop{body, label} => {
+				return self.loop_execution(body, label);
+			},
+			ASTNode::Try{body, catches, finally} => {
+				return self.try_execution(body, catches, finally);
+			},
+			ASTNode::Throw{value, line} => {
+				self.throw_statement((*value).clone(), line);
+			},
+			ASTNode::For {loop_var, object, body, label} => {
+				//For {loop_var: Rc<ASTNode>, object: Rc<ASTNode>, body:Vec<ASTNode>},
+				return self.for_execution((*loop_var).clone(), (*object).clone(), body, label);
+			},
+			ASTNode::Struct {name, attributes} => {
+				//Class { name: Rc<ASTNode>,  parent_classes:Option<Vec<ASTNode>>, block:Vec<ASTNode> },
+				return self.struct_execution((*name).clone(), attributes);
+			},
+			ASTNode::Impl{ name, block } => {
+				return self.impl_execution((*name).clone(), block);
+			},
+			_ => {
+				let value = self.evaluate(statement);
+				return self.value2_lazy(value);
+			}
+		}
+	}
+
+	fn impl_execution(&mut self, name: ASTNode, block:Vec<ASTNode>) -> LazyResult {
+		let struct_name = match name {
+			ASTNode::ID{ref name, ..} => {
+				name.to_string()
+			},
+			_ => {
+				self.raise("RuntimeError", String::from("Invalid Class name"));
+			}
+		};
+
+		self.structs_impl.insert(struct_name, block);
+		return LazyResult::Null;
+	}
+
+	fn struct_execution(&mut self, name: ASTNode, attributes: Vec<ASTNode>) -> LazyResult {
+		let struct_name = match name {
+			ASTNode::ID{ref name, ..} => {
+				name
+			},
+			_ => {
+				self.raise("RuntimeError", String::from("Invalid Class name"));
+			}
+		};
+		
+		self.structs.insert(struct_name.to_string(), attributes);
+		
+		return LazyResult::Null;
+	}
+		
+	fn for_execution(&mut self, loop_var: ASTNode, object: ASTNode, body:Vec<ASTNode>, label: Option<String>) -> LazyResult {
+		let value = self.evaluate(object);
+		let lazy_value = self.value2_lazy(value);
+		let lazy_value = self.iter_source(lazy_value);
+
+		let mut new_scope: HashMap<String, Option<LazyResult>> = HashMap::new();
+
+		let var_name = match loop_var {
+			ASTNode::ID{ref name, ..} => {
+				name
+			},
+			_ => {
+				self.raise("RuntimeError", String::from("Invalid Variable: For loop(loop variable)"));
+			}
+		};
+
+		// Generators are run with the loop body consuming each value as it is yielded
+		if let LazyResult::Generator(ref generator) = lazy_value {
+			let sink = Sink::Loop{ loop_var: var_name.to_string(), body, label };
+			self.drive(generator, sink);
+			return LazyResult::Null;
+		}
+		let iterable: Vec<Value> = self.iterate(lazy_value);
+
+		// Every iteration gets a fresh scope holding the loop variable
+		for value in iterable {
+			let lazy_value = self.value2_lazy(value);
+			new_scope.insert(var_name.to_string(), Some(lazy_value));
+			self.set_scope(new_scope.clone());
+			
+			let _ = self.execute_block(body.clone());
+			self.clean_scope();
+
+			if self.loop_exit(&label).is_some() { break }
+		}
+
+		return LazyResult::Null;
+	}
+
+	fn while_execution(&mut self, condition: ASTNode, body: Vec<ASTNode>, label: Option<String>, body_first: bool) -> LazyResult {
+		// The condition is evaluated once before every iteration, `do { } while` skips the first check
+		let mut check = !body_first;
+
+		loop {
+			if check {
+				let value = self.evaluate(condition.clone());
+				let condition_value = self.value2_lazy(value);
+				if !self.truthy(&condition_value) { break }
+			}
+			check = true;
+
+			// Every iteration gets a fresh scope
+			self.set_scope(HashMap::new());
+			let _ = self.execute_block(body.clone());
+			self.clean_scope();
+
+			if self.loop_exit(&label).is_some() { break }
+		}
+		
+		return LazyResult::Null;
+	}
+
+	fn loop_execution(&mut self, body: Vec<ASTNode>, label: Option<String>) -> LazyResult {
+		// Runs until a `break`, its value is the value of the loop
+		loop {
+			self.set_scope(HashMap::new());
+			let _ = self.execute_block(body.clone());
+			self.clean_scope();
+
+			if let Some(value) = self.loop_exit(&label) {
+				return value;
+			}
+		}
+	}
+
+	fn loop_exit(&mut self, label: &Option<String>) -> Option<LazyResult> {
+		// After an iteration -> None while the loop goes on, else the value it stops with.
+		// A signal for this loop is used up here, one for a loop further out is left for it
+		if self.return_value.is_some() {
+			return Some(LazyResult::Null);
+		}
+		let targets = |target: &Option<String>| target.is_none() || target == label;
+
+		match self.signal.take() {
+			None => None,
+			Some(Signal::Continue(ref target)) if targets(target) => None,
+			Some(Signal::Break(ref target, ref value)) if targets(target) => {
+				Some(value.clone().map_or(LazyResult::Null, |value| self.value2_lazy(value)))
+			},
+			signal => {
+				self.signal = signal;
+				Some(LazyResult::Null)
+			}
+		}
+	}
+
+	fn match_execution(&mut self, option: ASTNode, cases: Vec<ASTNode>) -> LazyResult {
+		let option = self.evaluate(option);
+		let true_value = self.lazy2_value(LazyResult::Bool(true));
+		let mut value: Value;
+		let mut flag: bool;
+
+		for case in cases {
+			let ASTNode::Option {condition, block} = case else {
+				println!("(Int)Didn't find an option block");
+				self.fail();
+			};
+			flag = true;
+			
+			for partial_condition in condition {
+				value = self.evaluate(partial_condition);
+				
+				if value != option && value != true_value {
+					flag = false;
+					break;
+				}
+			}
+
+			if flag {
+				self.set_scope(HashMap::new());
+				let _ = self.execute_block(block);
+				self.clean_scope();
+				break;
+			}
+		}
+		return LazyResult::Null;
+	}
+
+	fn if_execution(&mut self, condition: ASTNode, if_block: Vec<ASTNode>, else_block: Option<Vec<ASTNode>>) -> LazyResult {
+		let value = self.evaluate(condition);
+		let result: LazyResult = self.value2_lazy(value);
+		let condition = self.truthy(&result);
+		
+		// Each branch is a block scope, the value of its last statement is the value of the `if`
+		let block = if condition { Some(if_block) } else { else_block };
+		let mut result = LazyResult::Null;
+
+		if let Some(block) = block {
+			self.set_scope(HashMap::new());
+			result = self.block_value(block);
+			self.clean_scope();
+		}
+
+		return result;
+	}
+
+	fn rn_statement(&mut self, list: Vec<ASTNode>) -> LazyResult {
+		// Tail call -> rn f(n - 1, acc)
+		// The arguments are evaluated here, the call itself is made by invoke_func
+		// once this function's scope is gone, so the stack does not grow
+		if let [ASTNode::FunctionCall{ref name, ref args}] = list[..] {
+			if self.call_depth > 0 && self.try_frames.last() != Some(&self.frames.len()) {
+				if let Some(call) = self.resolve_call(name) {
+					let args = self.evaluate_now(args.clone());
+					self.tail_call = Some((call, args));
+					self.return_value = Some(self.lazy2_value(LazyResult::None));
+
+					return LazyResult::Null;
+				}
+			}
+		}
+
+		if list.len() == 0 {
+			self.return_value = Some(self.lazy2_value(LazyResult::None));
+		} else if list.len() == 1 {
+			let expression: ASTNode = list[0].clone();
+			let value = self.evaluate(expression);
+			self.return_value = Some(value);
+		} else {
+			let expressions: Value = Value {
+				int_value: None,
+				float_value: None,
+				bool_value: None,
+				string_value: None,
+				list_value: Some(
+								list
+								.into_iter()
+								.map(|exp| self.evaluate(exp))
+								.collect()
+				),
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
+				value_type: 5_u8
+			};
+			
+			self.return_value = Some(expressions);
+		}
+			
+		return LazyResult::Null;
+	}
+
+	fn func_call(&mut self, name: &Rc<ASTNode>, args: Vec<ASTNode>) -> LazyResult {
+		let func_name: &str = match **name {
+			ASTNode::ID{ref name, ..} => {
+				name
+			},
+			_ => {
+				// method call -> object.method(args)
+				// or any expression evaluating to a function -> make_adder(1)(2)
+				let call = self.resolve_call(name).unwrap();
+				return self.invoke_func(call, args);
+			}
+		};
+
+		if BUILTIN_FUNCTIONS.contains(&func_name) {
+			match func_name {
+				 "print" | "println" => {
+					let mut result = String::new();
+					
+					for arg in self.evaluate_args(args) {
+						let value: String = arg.to_string();
+						result.push_str(value.as_str());
+					}
+					return match func_name {
+						"print" =>  self.print(result),
+						_ =>  self.println(result)
+					}
+				},
+				"type" | "int" | "float" | "str" | "bool" | "len" |
+				"is_int" | "is_float" | "is_bool" | "is_str" | "is_none" | "is_vector" => {
+					let mut args = self.evaluate_args(args);
+
+					if args.len() != 1 {
+						let verb  = if args.len() > 1 {	"were" } else { "was" };
+						self.raise("TypeError", format!(
+							"Builtin Function '{func_name}(..)' expects 1 argument, but {} {verb} provided",
+							args.len()
+						));
+					}
+					return self.type_builtin(func_name, args.remove(0));
+				},
+				"range" | "map" | "filter" | "reduce" | "any" | "all" | "sum" |
+				"min" | "max" | "sorted" | "zip" | "enumerate" | "take" | "skip" => {
+					return self.collection_builtin(func_name, args);
+				},
+				_ => {
+					println!("Builtin Function: {func_name} has not been implemented.");
+
+					return LazyResult::Null;
+				}
+			}
+		} else if self.structs.contains_key(func_name) {
+			return self.struct_construction(func_name, args);
+		} else {
+			let call = self.resolve_call(name).unwrap();
+			return self.invoke_func(call, args);
+		}
+	}
+
+	fn resolve_call(&mut self, callee: &Rc<ASTNode>) -> Option<Call> {
+		// Finds the function a call refers to, without calling it.
+		// Builtins and struct constructors are not functions -> None
+		match **callee {
+			ASTNode::ID{ref name, ..} => {
+				if BUILTIN_FUNCTIONS.contains(&name.as_str()) || self.structs.contains_key(name) {
+					return None;
+				}
+				if self.find_variable(name).is_some() {
+					// A variable holding a function shadows named functions
+					let value = self.evaluate((**callee).clone());
+					let callee = self.value2_lazy(value);
+
+					return Some(self.function_call(callee));
+				}
+				Some(self.named_call(name))
+			},
+			ASTNode::PropertyAccess{ref object, ref property} => {
+				let value = self.evaluate((**object).clone());
+				let receiver = self.value2_lazy(value);
+
+				let ASTNode::ID{name: ref method, ..} = **property else {
+					println!("Invalid method name");
+					self.fail();
+				};
+				Some(self.method_call(receiver, method))
+			},
+			_ => {
+				let value = self.evaluate((**callee).clone());
+				let callee = self.value2_lazy(value);
+
+				Some(self.function_call(callee))
+			}
+		}
+	}
+
+	fn evaluate_now(&mut self, args: Vec<ASTNode>) -> Vec<ASTNode> {
+		// Evaluates call arguments in the current scope -> Constant and Keyword{Constant} nodes
+		let mut constants: Vec<ASTNode> = vec![];
+		let mut positional: Vec<ASTNode> = vec![];
+
+		for arg in args {
+			if let ASTNode::Keyword{name, value} = arg {
+				let value = self.evaluate((*value).clone());
+				constants.push(ASTNode::Keyword{ name, value: Rc::new(ASTNode::Constant{ value }) });
+			} else {
+				positional.push(arg);
+			}
+		}
+		let values = self.evaluate_args(positional);
+		let mut args: Vec<ASTNode> = values.into_iter().map(|value| ASTNode::Constant{ value }).collect();
+
+		// Keep keyword arguments last, their relative order is all that matters
+		args.extend(constants);
+		args
+	}
+
+	fn type_builtin(&mut self, func_name: &str, value: Value) -> LazyResult {
+		// Conversion and introspection builtins, dispatched on the value type tag
+		let lazy_value = self.value2_lazy(value.clone());
+		let type_name = self.type_name(&lazy_value);
+
+		match func_name {
+			"type" => LazyResult::Str(type_name),
+			"str" => LazyResult::Str(value.to_string()),
+			"bool" => LazyResult::Bool(self.truthy(&lazy_value)),
+			"is_int" => LazyResult::Bool(value.value_type == 0),
+			"is_float" => LazyResult::Bool(value.value_type == 1),
+			"is_bool" => LazyResult::Bool(value.value_type == 2),
+			"is_str" => LazyResult::Bool(value.value_type == 3),
+			"is_none" => LazyResult::Bool(value.value_type == 4),
+			"is_vector" => LazyResult::Bool(value.value_type == 5),
+			"len" => {
+				match lazy_value {
+					LazyResult::Str(value) => LazyResult::Int(value.chars().count() as i32),
+					LazyResult::List(value) => LazyResult::Int(value.len() as i32),
+					_ => {
+						self.raise("TypeError", format!("Type `{type_name}` has no len()"));
+					}
+				}
+			},
+			"int" => {
+				match lazy_value {
+					LazyResult::Int(value) => LazyResult::Int(value),
+					// Truncated toward zero, NaN, infinities and values past the Int range have no Int
+					LazyResult::Float(value) if value.is_finite() && (i32::MIN as f64..=i32::MAX as f64).contains(&value.trunc()) => {
+						LazyResult::Int(value as i32)
+					},
+					LazyResult::Float(value) => {
+						self.raise("ValueError", format!("Cannot convert Float `{value}` to Int, it is out of range"));
+					},
+					LazyResult::Bool(value) => LazyResult::Int(value as i32),
+					LazyResult::Str(ref value) => {
+						match value.trim().parse::<i32>() {
+							Ok(value) => LazyResult::Int(value),
+							Err(_) => {
+								self.raise("ValueError", format!("Cannot convert Str {value:?} to Int"));
+							}
+						}
+					},
+					_ => {
+						self.raise("TypeError", format!("Cannot convert `{type_name}` to Int"));
+					}
+				}
+			},
+			_ => {
+				match lazy_value {
+					LazyResult::Int(value) => LazyResult::Float(value as f64),
+					LazyResult::Float(value) => LazyResult::Float(value),
+					LazyResult::Bool(value) => LazyResult::Float(if value { 1.0 } else { 0.0 }),
+					LazyResult::Str(ref value) => {
+						match value.trim().parse::<f64>() {
+							Ok(value) => LazyResult::Float(value),
+							Err(_) => {
+								self.raise("ValueError", format!("Cannot convert Str {value:?} to Float"));
+							}
+						}
+					},
+					_ => {
+						self.raise("TypeError", format!("Cannot convert `{type_name}` to Float"));
+					}
+				}
+			}
+		}
+	}
+
+	fn truthy(&mut self, value: &LazyResult) -> bool {
+		// Truthiness protocol used by `if`, `while`, `!`, `&&`, `||` and bool()
+		//   falsy  -> None, False, 0, 0.0, "" and []
+		//   truthy -> everything else
+		// A struct is truthy unless its impl has a `bool(self)` method returning False
+		match value {
+			LazyResult::Bool(value) => *value,
+			LazyResult::Int(value) => *value != 0,
+			LazyResult::Float(value) => *value != 0.0,
+			LazyResult::Str(value) => !value.is_empty(),
+			LazyResult::List(value) => !value.is_empty(),
+			LazyResult::Struct(instance) => {
+				let struct_name = instance.borrow().name.clone();
+
+				if self.find_method(&struct_name, "bool").is_none() {
+					return true;
+				}
+				match self.call_method(value.clone(), "bool", vec![]) {
+					LazyResult::Bool(value) => value,
+					other => {
+						self.raise("TypeError", format!(
+							"`{struct_name}.bool()` must return Bool, found `{}`",
+							self.type_name(&other)
+						));
+					}
+				}
+			},
+			LazyResult::None | LazyResult::Null => false,
+			LazyResult::Function(..) | LazyResult::Generator(..) | LazyResult::Expression{..} => true,
+			LazyResult::Shared(..) => unreachable!("cells are read through find_variable"),
+		}
+	}
+
+	fn collection_builtin(&mut self, func_name: &str, args: Vec<ASTNode>) -> LazyResult {
+		// range, map, filter, reduce, any, all, sum, min, max, sorted, zip, enumerate
+		// Callbacks are called like any other Mar function
+		let mut positional: Vec<ASTNode> = vec![];
+		let mut keywords: HashMap<String, Value> = HashMap::new();
+
+		for arg in args {
+			if let ASTNode::Keyword{name, value} = arg {
+				let value = self.evaluate((*value).clone());
+				keywords.insert(name, value);
+			} else {
+				positional.push(arg);
+			}
+		}
+		let mut args: Vec<Value> = self.evaluate_args(positional);
+
+		let (min_args, max_args, allowed): (usize, usize, &[&str]) = match func_name {
+			"range" => (1, 3, &[]),
+			"map" | "filter" | "take" | "skip" => (2, 2, &[]),
+			"reduce" => (2, 3, &[]),
+			"any" | "all" => (1, 2, &[]),
+			"sum" => (1, 1, &[]),
+			"min" | "max" => (1, 1, &["key"]),
+			"sorted" => (1, 1, &["key", "reverse"]),
+			"enumerate" => (1, 1, &["start"]),
+			_ => (1, usize::MAX, &[])
+		};
+		if args.len() < min_args || args.len() > max_args {
+			self.raise("TypeError", format!(
+				"Builtin Function '{func_name}(..)' expects {} arguments, but {} were provided",
+				if min_args == max_args { min_args.to_string() } else { format!("{min_args} to {max_args}") },
+				args.len()
+			));
+		}
+		if let Some(name) = keywords.keys().find(|name| !allowed.contains(&name.as_str())) {
+			self.raise("TypeError", format!("Builtin Function '{func_name}' got an unexpected keyword argument `{name}`"));
+		}
+
+		if func_name == "range" {
+			// range(stop) | range(start, stop) | range(start, stop, step)
+			let mut bounds: Vec<i32> = vec![];
+			for arg in args {
+				match self.value2_lazy(arg) {
+					LazyResult::Int(value) => bounds.push(value),
+					other => {
+						self.raise("TypeError", format!("range() expects Int arguments, found `{}`", self.type_name(&other)));
+					}
+				}
+			}
+			let (start, stop, step) = match bounds[..] {
+				[stop] => (0, stop, 1),
+				[start, stop] => (start, stop, 1),
+				_ => (bounds[0], bounds[1], bounds[2])
+			};
+			if step == 0 {
+				self.raise("ValueError", String::from("range() step must not be zero"));
+			}
+
+			let mut values: Vec<Value> = vec![];
+			let mut i = start;
+			while (step > 0 && i < stop) || (step < 0 && i > stop) {
+				values.push(self.lazy2_value(LazyResult::Int(i)));
+
+				// Past the largest (or smallest) Int the range is over anyway
+				let Some(next) = i.checked_add(step) else { break };
+				i = next;
+			}
+			return LazyResult::List(values);
+		}
+
+		if func_name == "zip" {
+			let sources: Vec<LazyResult> = args
+				.into_iter()
+				.map(|arg| {
+					let arg = self.value2_lazy(arg);
+					self.iter_source(arg)
+				})
+				.collect();
+
+			// Zipping a generator stays lazy
+			if let Some(LazyResult::Generator(source)) = sources.iter().find(|source| matches!(source, LazyResult::Generator(..))) {
+				let source = source.clone();
+				return LazyResult::Generator(Rc::new(Generator::Zip{ source, args: sources }));
+			}
+			let iterables: Vec<Vec<Value>> = sources.into_iter().map(|source| self.iterate(source)).collect();
+			let length = iterables.iter().map(|items| items.len()).min().unwrap_or(0);
+
+			let tuples: Vec<Value> = (0..length)
+				.map(|i| {
+					let tuple = iterables.iter().map(|items| items[i].clone()).collect();
+					self.lazy2_value(LazyResult::List(tuple))
+				})
+				.collect();
+			return LazyResult::List(tuples);
+		}
+
+		let iterable = args.remove(0);
+		let iterable = self.value2_lazy(iterable);
+		let iterable = self.iter_source(iterable);
+
+		if ["take", "skip"].contains(&func_name) {
+			let count = match self.value2_lazy(args.remove(0)) {
+				LazyResult::Int(count) if count >= 0 => count as usize,
+				other => {
+					self.raise("TypeError", format!("{func_name}() expects a count >= 0, found `{}`", self.type_name(&other)));
+				}
+			};
+			return match (func_name, iterable) {
+				("take", LazyResult::Generator(source)) => LazyResult::Generator(Rc::new(Generator::Take{ source, count })),
+				(_, LazyResult::Generator(source)) => LazyResult::Generator(Rc::new(Generator::Skip{ source, count })),
+				("take", iterable) => LazyResult::List(self.iterate(iterable).into_iter().take(count).collect()),
+				(_, iterable) => LazyResult::List(self.iterate(iterable).into_iter().skip(count).collect())
+			};
+		}
+
+		let start = match keywords.get("start").map(|start| start.int_value) {
+			Some(Some(start)) => start,
+			Some(None) => {
+				self.raise("TypeError", String::from("enumerate() start must be an Int"));
+			},
+			None => 0
+		};
+
+		// map, filter and enumerate over a generator stay lazy, any and all stop at the first item deciding them
+		if let LazyResult::Generator(ref source) = iterable {
+			let source = source.clone();
+
+			match func_name {
+				"map" | "filter" => {
+					let function = self.value2_lazy(args[0].clone());
+
+					return LazyResult::Generator(Rc::new(match func_name {
+						"map" => Generator::Map{ source, function },
+						_ => Generator::Filter{ source, function }
+					}));
+				},
+				"enumerate" => return LazyResult::Generator(Rc::new(Generator::Enumerate{ source, start })),
+				"any" | "all" => {
+					let want = func_name == "any";
+					let function = args.pop().map(|function| self.value2_lazy(function));
+
+					return match self.drive(&source, Sink::Any{ want, function, found: false }) {
+						Sink::Any{found, ..} => LazyResult::Bool(found == want),
+						_ => unreachable!()
+					};
+				},
+				_ => ()
+			}
+		}
+		let items: Vec<Value> = self.iterate(iterable);
+		let callback: Option<LazyResult> = if args.is_empty() { None } else {
+			let callback = args.remove(0);
+			Some(self.value2_lazy(callback))
+		};
+
+		match func_name {
+			"map" => {
+				let callback = callback.unwrap();
+				let values = items.into_iter().map(|item| self.call_with(&callback, vec![item])).collect();
+				LazyResult::List(values)
+			},
+			"filter" => {
+				let callback = callback.unwrap();
+				let mut values: Vec<Value> = vec![];
+
+				for item in items {
+					let keep = self.call_with(&callback, vec![item.clone()]);
+					let keep = self.value2_lazy(keep);
+
+					if self.truthy(&keep) {
+						values.push(item);
+					}
+				}
+				LazyResult::List(values)
+			},
+			"reduce" => {
+				// reduce(iterable, f, initial) -> f(f(initial, a), b) ...
+				let callback = callback.unwrap();
+				let mut items = items.into_iter();
+
+				let mut accumulator = match args.pop() {
+					Some(initial) => initial,
+					None => match items.next() {
+						Some(first) => first,
+						None => {
+							self.raise("ValueError", String::from("reduce() of an empty sequence with no initial value"));
+						}
+					}
+				};
+				for item in items {
+					accumulator = self.call_with(&callback, vec![accumulator, item]);
+				}
+				self.value2_lazy(accumulator)
+			},
+			"any" | "all" => {
+				// Short-circuits on the first item that decides the result
+				let want = func_name == "any";
+
+				for item in items {
+					let value = match callback {
+						Some(ref callback) => self.call_with(callback, vec![item]),
+						None => item
+					};
+					let value = self.value2_lazy(value);
+
+					if self.truthy(&value) == want {
+						return LazyResult::Bool(want);
+					}
+				}
+				LazyResult::Bool(!want)
+			},
+			"sum" => {
+				let mut items = items.into_iter();
+				let Some(mut total) = items.next() else {
+					return LazyResult::Int(0);
+				};
+
+				for item in items {
+					total = self.binary_values(total, "+", item);
+				}
+				self.value2_lazy(total)
+			},
+			"min" | "max" => {
+				if items.is_empty() {
+					self.raise("ValueError", format!("{func_name}() of an empty sequence"));
+				}
+				let keys = self.sort_keys(&items, keywords.get("key"));
+				let operation = if func_name == "min" { "<" } else { ">" };
+
+				let mut best = 0;
+				for i in 1..items.len() {
+					if self.compare_values(&keys[i], operation, &keys[best]) {
+						best = i;
+					}
+				}
+				let best = items[best].clone();
+				self.value2_lazy(best)
+			},
+			"sorted" => {
+				// Stable sort by key -> sorted(items, key = f, reverse = True)
+				let keys = self.sort_keys(&items, keywords.get("key"));
+				let reverse = match keywords.get("reverse") {
+					Some(reverse) => {
+						let reverse = self.value2_lazy(reverse.clone());
+						self.truthy(&reverse)
+					},
+					None => false
+				};
+				let operation = if reverse { ">" } else { "<" };
+
+				let mut order: Vec<usize> = (0..items.len()).collect();
+				order.sort_by(|&a, &b| {
+					if self.compare_values(&keys[a], operation, &keys[b]) {
+						std::cmp::Ordering::Less
+					} else if self.compare_values(&keys[b], operation, &keys[a]) {
+						std::cmp::Ordering::Greater
+					} else {
+						std::cmp::Ordering::Equal
+					}
+				});
+				LazyResult::List(order.into_iter().map(|i| items[i].clone()).collect())
+			},
+			_ => {
+				// enumerate(items, start = 0) -> [[0, a], [1, b] ...]
+				let pairs = items
+					.into_iter()
+					.enumerate()
+					.map(|(i, item)| {
+						let index = self.lazy2_value(LazyResult::Int(start + i as i32));
+						self.lazy2_value(LazyResult::List(vec![index, item]))
+					})
+					.collect();
+				LazyResult::List(pairs)
+			}
+		}
+	}
+
+	fn iter_source(&mut self, value: LazyResult) -> LazyResult {
+		// Structs with an `iter(self)` method are iterated through what it returns
+		match value {
+			LazyResult::Struct(ref instance) if self.find_method(&instance.borrow().name.clone(), "iter").is_some() => {
+				let items = self.call_method(value.clone(), "iter", vec![]);
+				self.iter_source(items)
+			},
+			_ => value
+		}
+	}
+
+	fn iterate(&mut self, value: LazyResult) -> Vec<Value> {
+		// Iteration protocol -> Vectors, Str (per character), generators and structs with an `iter(self)` method
+		match self.iter_source(value) {
+			LazyResult::List(items) => items,
+			LazyResult::Str(value) => {
+				value.chars().map(|chr| self.lazy2_value(LazyResult::Str(chr.to_string()))).collect()
+			},
+			LazyResult::Generator(generator) => {
+				match self.drive(&generator, Sink::Collect(vec![])) {
+					Sink::Collect(items) => items,
+					_ => vec![]
+				}
+			},
+			value => {
+				self.raise("TypeError", format!("Type `{}` is not iterable", self.type_name(&value)));
+			}
+		}
+	}
+
+	fn drive(&mut self, generator: &Generator, sink: Sink) -> Sink {
+		// Runs a generator, every yielded value goes through `sink`. Returns the sink once it is done
+		match generator {
+			Generator::Take{source, count} => {
+				if *count == 0 {
+					return sink;
+				}
+				match self.drive(source, Sink::Take{ remaining: *count, then: Box::new(sink) }) {
+					Sink::Take{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Skip{source, count} => {
+				match self.drive(source, Sink::Skip{ remaining: *count, then: Box::new(sink) }) {
+					Sink::Skip{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Map{source, function} => {
+				match self.drive(source, Sink::Map{ function: function.clone(), then: Box::new(sink) }) {
+					Sink::Map{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Filter{source, function} => {
+				match self.drive(source, Sink::Filter{ function: function.clone(), then: Box::new(sink) }) {
+					Sink::Filter{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Enumerate{source, start} => {
+				match self.drive(source, Sink::Enumerate{ index: *start, then: Box::new(sink) }) {
+					Sink::Enumerate{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Zip{source, args} => {
+				// The other arguments are read when it starts, no further than the shortest Vector or Str
+				let bound = args.iter().filter_map(|arg| match arg {
+					LazyResult::List(items) => Some(items.len()),
+					LazyResult::Str(value) => Some(value.chars().count()),
+					_ => None
+				}).min();
+
+				let mut columns: Vec<Option<Vec<Value>>> = vec![];
+				for arg in args {
+					columns.push(match (arg, bound) {
+						(LazyResult::Generator(generator), _) if Rc::ptr_eq(generator, source) => None,
+						(LazyResult::Generator(generator), Some(count)) => {
+							let limited = Generator::Take{ source: generator.clone(), count };
+							Some(self.iterate(LazyResult::Generator(Rc::new(limited))))
+						},
+						(arg, _) => Some(self.iterate(arg.clone()))
+					});
+				}
+				if columns.iter().flatten().any(|items| items.is_empty()) {
+					return sink;
+				}
+				match self.drive(source, Sink::Zip{ columns, index: 0, then: Box::new(sink) }) {
+					Sink::Zip{then, ..} => *then,
+					_ => unreachable!()
+				}
+			},
+			Generator::Call{call, args} => {
+				self.consumers.push(Consumer {
+					frames: self.frames.len(),
+					scopes: self.scopes.len(),
+					trying: self.trying,
+					sink: Some(sink),
+					returned: None,
+					signal: None
+				});
+				self.enter_call(&call.name);
+				let _ = self.run_call(call.clone(), args.clone());
+				self.call_depth -= 1;
+
+				// A generator ends at `rn`, it is never a tail call
+				self.tail_call = None;
+
+				let consumer = self.consumers.pop().unwrap();
+				if consumer.returned.is_some() {
+					self.return_value = consumer.returned;
+				}
+				if consumer.signal.is_some() {
+					self.signal = consumer.signal;
+				}
+				consumer.sink.unwrap()
+			}
+		}
+	}
+
+	fn yield_statement(&mut self, value: ASTNode) -> LazyResult {
+		// Hands the value to the code consuming this generator, in that code's scopes.
+		// When the consumer is done (`break`, `take` ...) the generator stops like on `rn`
+		let value = self.evaluate(value);
+		let depth = self.frames.len();
+
+		let Some(index) = self.consumers.iter().rposition(|consumer| consumer.frames + 1 == depth) else {
+			self.raise("SyntaxError", String::from("`yield` can only be used inside a function"));
+		};
+		let mut sink = self.consumers[index].sink.take().unwrap();
+		let (frames, scopes) = (self.consumers[index].frames, self.consumers[index].scopes);
+
+		// Switch to the consumer's scopes
+		let generator_frames = self.frames.split_off(frames);
+		let generator_scopes = self.scopes.split_off(scopes + 1);
+		let consumer_scope = self.scopes.pop().unwrap();
+		let generator_scope = std::mem::replace(&mut self.current_scope, consumer_scope);
+		let trying = std::mem::replace(&mut self.trying, self.consumers[index].trying);
+
+		let mut more = self.feed(&mut sink, value);
+		self.trying = trying;
+
+		if self.return_value.is_some() {
+			self.consumers[index].returned = self.return_value.take();
+			more = false;
+		}
+		if self.signal.is_some() {
+			self.consumers[index].signal = self.signal.take();
+			more = false;
+		}
+
+		// And back to the generator's
+		let consumer_scope = std::mem::replace(&mut self.current_scope, generator_scope);
+		self.scopes.push(consumer_scope);
+		self.scopes.extend(generator_scopes);
+		self.frames.extend(generator_frames);
+		self.consumers[index].sink = Some(sink);
+
+		if !more {
+			self.return_value = Some(self.lazy2_value(LazyResult::None));
+		}
+		return LazyResult::Null;
+	}
 
-    fn execute_statement(&mut self, statement: ASTNode) -> LazyResult {
-		match statement {
-			ASTNode::Var{name, value} => {
-				return self.var_declaration(&name, value);
-			},
-			ASTNode::Function{name, parameters, block} => {
-				return self.func_declaration(name, parameters, block);
+	fn feed(&mut self, sink: &mut Sink, value: Value) -> bool {
+		// Passes one value down the sink, false once no more values are wanted
+		match sink {
+			Sink::Loop{loop_var, body, label} => {
+				let mut scope: HashMap<String, Option<LazyResult>> = HashMap::new();
+				let value = self.value2_lazy(value);
+				scope.insert(loop_var.to_string(), Some(value));
+
+				self.set_scope(scope);
+				let _ = self.execute_block(body.clone());
+				self.clean_scope();
+
+				self.loop_exit(label).is_none()
 			},
-			ASTNode::Return{ list } => {
-				return self.rn_statement(list);
+			Sink::Collect(items) => {
+				items.push(value);
+				true
 			},
-			ASTNode::If{condition, if_block, else_block} => {
-				return self.if_execution((*condition).clone(), if_block, else_block);
+			Sink::Take{remaining, then} => {
+				*remaining -= 1;
+				let more = self.feed(then, value);
+				more && *remaining > 0
 			},
-			ASTNode::Match{option, cases} => {
-				return self.match_execution((*option).clone(), cases);
+			Sink::Skip{remaining, then} => {
+				if *remaining > 0 {
+					*remaining -= 1;
+					return true;
+				}
+				self.feed(then, value)
 			},
-			ASTNode::While{condition, body} => {
-				return self.while_execution((*condition).clone(), body);
+			Sink::Map{function, then} => {
+				let value = self.call_with(function, vec![value]);
+				self.feed(then, value)
 			},
-			ASTNode::For {loop_var, object, body} => {
-				//For {loop_var: Rc<ASTNode>, object: Rc<ASTNode>, body:Vec<ASTNode>},
-				return self.for_execution((*loop_var).clone(), (*object).clone(), body);
+			Sink::Filter{function, then} => {
+				let keep = self.call_with(function, vec![value.clone()]);
+				let keep = self.value2_lazy(keep);
+
+				if self.truthy(&keep) { self.feed(then, value) } else { true }
 			},
-			ASTNode::Struct {name, attributes} => {
-				//Class { name: Rc<ASTNode>,  parent_classes:Option<Vec<ASTNode>>, block:Vec<ASTNode> },
-				return self.struct_execution((*name).clone(), attributes);
+			Sink::Enumerate{index, then} => {
+				let position = self.lazy2_value(LazyResult::Int(*index));
+				let pair = self.lazy2_value(LazyResult::List(vec![position, value]));
+				*index += 1;
+
+				self.feed(then, pair)
 			},
-			ASTNode::Impl{ name, block } => {
-				return self.impl_execution((*name).clone(), block);
+			Sink::Zip{columns, index, then} => {
+				let tuple = columns
+					.iter()
+					.map(|column| match column {
+						Some(items) => items[*index].clone(),
+						None => value.clone()
+					})
+					.collect();
+				let tuple = self.lazy2_value(LazyResult::List(tuple));
+				*index += 1;
+
+				let more = self.feed(then, tuple);
+				more && columns.iter().flatten().all(|items| *index < items.len())
 			},
-			_ => {
-				let value = self.evaluate(statement);
-				return self.value2_lazy(value);
+			Sink::Any{want, function, found} => {
+				let value = match function {
+					Some(function) => self.call_with(function, vec![value]),
+					None => value
+				};
+				let value = self.value2_lazy(value);
+
+				*found = self.truthy(&value) == *want;
+				!*found
 			}
 		}
 	}
 
-	fn impl_execution(&mut self, name: ASTNode, block:Vec<ASTNode>) -> LazyResult {
-		let struct_name = match name {
-			ASTNode::ID{ref name} => {
-				name.to_string()
-			},
-			_ => {
-				println!("RTE: Invalid Class name");
-				std::process::exit(1);
-			}
-		};
+	fn call_with(&mut self, callee: &LazyResult, args: Vec<Value>) -> Value {
+		let args: Vec<ASTNode> = args.into_iter().map(|value| ASTNode::Constant{ value }).collect();
+		let result = self.call_value(callee.clone(), args);
 
-		self.structs_impl.insert(struct_name, block);
-		return LazyResult::Null;
+		self.lazy2_value(result)
 	}
 
-	fn struct_execution(&mut self, name: ASTNode, attributes: Vec<ASTNode>) -> LazyResult {
-		let struct_name = match name {
-			ASTNode::ID{ref name} => {
-				name
-			},
-			_ => {
-				println!("RTE: Invalid Class name");
-				std::process::exit(1);
-			}
-		};
-		
-		self.structs.insert(struct_name.to_string(), attributes);
-		
-		return LazyResult::Null;
+	fn binary_values(&mut self, left: Value, operation: &str, right: Value) -> Value {
+		let left = Rc::new(ASTNode::Constant{ value: left });
+		let right = Rc::new(ASTNode::Constant{ value: right });
+
+		self.evaluate_binary_expression(left, operation.to_string(), right)
+	}
+
+	fn compare_values(&mut self, left: &Value, operation: &str, right: &Value) -> bool {
+		let result = self.binary_values(left.clone(), operation, right.clone());
+		result.bool_value == Some(true)
 	}
-		
-	fn for_execution(&mut self, loop_var: ASTNode, object: ASTNode, body:Vec<ASTNode>) -> LazyResult {
-		let value = self.evaluate(object);
 
-		let iterable: Vec<Value> = match self.value2_lazy(value) {
-			LazyResult::List(value) => {
-				value
+	fn sort_keys(&mut self, items: &[Value], key: Option<&Value>) -> Vec<Value> {
+		match key {
+			Some(key) => {
+				let key = self.value2_lazy(key.clone());
+				items.iter().map(|item| self.call_with(&key, vec![item.clone()])).collect()
 			},
-			_ => {
-				println!("Iterable must be a Vector or something Iterable");
-				std::process::exit(1);
-			}
-		};
+			None => items.to_vec()
+		}
+	}
 
-		let mut new_scope: HashMap<String, Option<LazyResult>> = HashMap::new();
+	fn call_value(&mut self, callee: LazyResult, args: Vec<ASTNode>) -> LazyResult {
+		let call = self.function_call(callee);
+		return self.invoke_func(call, args);
+	}
 
-		let var_name = match loop_var {
-			ASTNode::ID{ref name} => {
-				name
-			},
-			_ => {
-				println!("RTE: Invalid Variable: For loop(loop variable)");
-				std::process::exit(1);
-			}
+	fn function_call(&mut self, callee: LazyResult) -> Call {
+		let LazyResult::Function(ref function) = callee else {
+			self.raise("TypeError", format!("Type `{}` is not callable", self.type_name(&callee)));
 		};
+		let mut environment = function.environment.clone();
 
-		new_scope.insert(var_name.to_string(), None);
-		self.set_scope(new_scope);
-		
-		for value in iterable {
-			if self.continue_loop { 
-				self.continue_loop = false;
-				continue
-			}
-
-			let lazy_value = self.value2_lazy(value);
-			self.current_scope.insert(var_name.to_string(), Some(lazy_value));
-			
-			let flag = self.execute_block(body.clone());
-			if self.break_loop || flag { break }				
+		// A named function can call itself, even when it is local to a block
+		if function.name != "<lambda>" && !environment.contains_key(&function.name) {
+			environment.insert(function.name.clone(), Some(callee.clone()));
 		}
-		if self.break_loop {self.break_loop = false;}
 
-		self.clean_scope();
-		return LazyResult::Null;
+		Call {
+			name: function.name.clone(),
+			parameters: function.parameters.clone(),
+			block: function.block.clone(),
+			receiver: None,
+			environment
+		}
 	}
 
-	fn while_execution(&mut self, condition: ASTNode, body: Vec<ASTNode>) -> LazyResult {
-		let mut condition_value = self.evaluate(condition.clone());
-		let true_value = self.lazy2_value(LazyResult::Bool(true));
-		self.set_scope(HashMap::new());
+	fn evaluate_args(&mut self, args: Vec<ASTNode>) -> Vec<Value> {
+		// Evaluates arguments or Vector items, expanding `...vector` in place
+		let mut values: Vec<Value> = vec![];
 
-		while condition_value == true_value {
-			for line in body.clone() {
-				if self.continue_loop { 
-					self.continue_loop = false;
-					continue
+		for arg in args {
+			match arg {
+				ASTNode::Spread{value} => {
+					let value = self.evaluate((*value).clone());
+
+					match self.value2_lazy(value) {
+						LazyResult::List(list) => values.extend(list),
+						other => {
+							self.raise("TypeError", format!("Cannot spread `{}`, only a Vector can be spread", self.type_name(&other)));
+						}
+					}
+				},
+				_ => {
+					let value = self.evaluate(arg);
+					values.push(value);
 				}
-			
-				let _ = self.execute_statement(line);
-				condition_value = self.evaluate(condition.clone());
-				
-				if self.break_loop || self.return_value.is_some() { break }
-			}
-			if self.break_loop || self.return_value.is_some() {
-				self.break_loop = false;
-				break
 			}
 		}
-		
-		self.clean_scope();
-		return LazyResult::Null;
+		values
 	}
 
-	fn match_execution(&mut self, option: ASTNode, cases: Vec<ASTNode>) -> LazyResult {
-		let option = self.evaluate(option);
-		let true_value = self.lazy2_value(LazyResult::Bool(true));
-		let mut value: Value;
-		let mut flag: bool;
+	fn struct_construction(&mut self, struct_name: &str, args: Vec<ASTNode>) -> LazyResult {
+		// Car("Audi", "S8", 2019) -> attributes are assigned in order of declaration
+		let attributes = self.structs.get(struct_name).unwrap().clone();
+		let args = self.evaluate_args(args);
 
-		for case in cases {
-			let ASTNode::Option {condition, block} = case else {
-				println!("(Int)Didn't find an option block");
-				std::process::exit(1);
-			};
-			flag = true;
-			
-			for partial_condition in condition {
-				value = self.evaluate(partial_condition);
-				
-				if value != option && value != true_value {
-					flag = false;
-					break;
-				}
-			}
+		if attributes.len() != args.len() {
+			let verb  = if args.len() > 1 {	"were" } else { "was" };
+			self.raise("TypeError", format!(
+				"Struct '{struct_name}' expects {} arguments, but {} {verb} provided",
+				attributes.len(), args.len()
+			));
+		}
 
-			if flag {
-				let _ = self.execute_block(block);
-				break;
-			}
+		let mut fields: Vec<(String, Value)> = vec![];
+		for (attribute, arg) in attributes.iter().zip(args) {
+			let ASTNode::ID{ref name, ..} = attribute else {
+				self.raise("RuntimeError", format!("Invalid attribute in struct `{struct_name}`"));
+			};
+			fields.push((name.to_string(), arg));
 		}
-		return LazyResult::Null;
-	}
 
-	fn if_execution(&mut self, condition: ASTNode, if_block: Vec<ASTNode>, else_block: Option<Vec<ASTNode>>) -> LazyResult {
-		let value = self.evaluate(condition);
-		let result: LazyResult = self.value2_lazy(value);
+		LazyResult::Struct(Rc::new(RefCell::new(StructInstance { name: struct_name.to_string(), fields })))
+	}
 
-		let condition = match result {
-			LazyResult::Bool(value) => {
-				value
-			},
-			_ => {
-				println!("RTE: Invalid `If Condition`.\nCondition in a If statement are supposed to evaluate to true or false");
-				std::process::exit(1);
-			}
-		};
-		
-		if condition {
-			let _ = self.execute_block(if_block);
-		} else {
-			if let Some(else_clause) = else_block {
-				let _ = self.execute_block(else_clause);
+	fn find_method(&self, struct_name: &str, method: &str) -> Option<((Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), Vec<ASTNode>)> {
+		for statement in self.structs_impl.get(struct_name)? {
+			if let ASTNode::Function{name, parameters, block, ..} = statement {
+				if matches!(**name, ASTNode::ID{ref name, ..} if name == method) {
+					return Some((parameters.clone(), block.clone()));
+				}
 			}
 		}
-
-		return LazyResult::Null;
+		None
 	}
 
-	fn rn_statement(&mut self, list: Vec<ASTNode>) -> LazyResult {
-		if list.len() == 0 {
-			self.return_value = Some(self.lazy2_value(LazyResult::None));
-		} else if list.len() == 1 {
-			let expression: ASTNode = list[0].clone();
-			let value = self.evaluate(expression);
-			self.return_value = Some(value);
-		} else {
-			let expressions: Value = Value {
-				int_value: None,
-				float_value: None,
-				bool_value: None,
-				string_value: None,
-				list_value: Some(
-								list
-								.into_iter()
-								.map(|exp| self.evaluate(exp))
-								.collect()
-				),
-				value_type: 5_u8
-			};
-			
-			self.return_value = Some(expressions);
-		}
-			
-		return LazyResult::Null;
+	fn call_method(&mut self, receiver: LazyResult, method: &str, args: Vec<ASTNode>) -> LazyResult {
+		let call = self.method_call(receiver, method);
+		return self.invoke_func(call, args);
 	}
 
-	fn func_call(&mut self, name: &Rc<ASTNode>, args: Vec<ASTNode>) -> LazyResult {
-		let func_name: &str = match **name {
-			ASTNode::ID{ref name} => {
-				name
-			},
-			_ => {
-				println!("Name: {:?}", &name);
-				println!("Invalid function name");
-				std::process::exit(1);
-			}
+	fn method_call(&mut self, receiver: LazyResult, method: &str) -> Call {
+		let LazyResult::Struct(ref instance) = receiver else {
+			self.raise("AttributeError", format!("Type `{}` has no method `{method}`", self.type_name(&receiver)));
 		};
+		let struct_name = instance.borrow().name.clone();
 
-		if BUILTIN_FUNCTIONS.contains(&func_name) {
-			match func_name {
-				 "print" | "println" => {
-					let mut result = String::new();
-					
-					for arg in &args {
-						let value: String = self.evaluate(arg.clone()).to_string();
-						result.push_str(value.as_str());
-					}
-					return match func_name {
-						"print" =>  self.print(result),
-						_ =>  self.println(result)
-					}
-				},
-				_ => {
-					println!("Builtin Function: {func_name} has not been implemented.");
+		let Some((params, block)) = self.find_method(&struct_name, method) else {
+			// A field holding a function can be called like a method -> button.on_click()
+			let field = instance.borrow().fields.iter().find(|(name, _)| name == method).map(|(_, value)| value.clone());
 
-					return LazyResult::Null;
-				}
+			if let Some(field) = field {
+				let callee = self.value2_lazy(field);
+				return self.function_call(callee);
 			}
-		} else if self.structs.contains_key(func_name) {
-			// handle class constructor
-			todo!("Handle class constructor at line 1502");
-		} else {
-			return self.execute_func(func_name.to_string(), args);
+			self.raise("AttributeError", format!("Struct `{struct_name}` has no method `{method}`"));
+		};
+
+		Call {
+			name: format!("{struct_name}.{method}"),
+			parameters: params,
+			block,
+			receiver: Some(receiver),
+			environment: HashMap::new()
 		}
 	}
 
@@ -1524,6 +4316,9 @@ impl Executor {
 					bool_value: None,
 					string_value: None,
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 0_u8
 				}
 			},
@@ -1534,6 +4329,9 @@ impl Executor {
 					bool_value: None,
 					string_value: None,
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 1_u8
 				}
 			},
@@ -1544,6 +4342,9 @@ impl Executor {
 					bool_value: Some(value),
 					string_value: None,
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 2_u8
 				}
 			},
@@ -1554,6 +4355,9 @@ impl Executor {
 					bool_value: None,
 					string_value: Some(value),
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 3_u8
 				}
 			},
@@ -1564,6 +4368,9 @@ impl Executor {
 					bool_value: Some(true),
 					string_value: None,
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 2_u8
 				}
 			},
@@ -1574,17 +4381,23 @@ impl Executor {
 					bool_value: None,
 					string_value: None,
 					list_value: None,
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 4_u8
 				}
 			},
 			ASTNode::ExpressionList {list} => {
-				let value: Vec<Value> = list.into_iter().map(|x| self.evaluate(x.clone())).collect();
+				let value: Vec<Value> = self.evaluate_args(list);
 				Value {
 					int_value: None,
 					float_value: None,
 					bool_value: None,
 					string_value: None,
 					list_value: Some(value),
+					struct_value: None,
+					func_value: None,
+					generator_value: None,
 					value_type: 5_u8
 				}
 			},
@@ -1593,8 +4406,7 @@ impl Executor {
 				let vector_value: Vec<Value> = match self.value2_lazy(vector) {
 					LazyResult::List(value) => value,
 					_ => {
-						println!("RTE: Cannot Index object");
-						std::process::exit(1);
+						self.raise("TypeError", String::from("Cannot Index object"));
 					}
 				};
 				
@@ -1606,8 +4418,7 @@ impl Executor {
 							let len_check = ((vector_value.len() as i32)) + value;
 							
 							if len_check < 0 {
-								println!("RTE: Invalid index to a vector with length `{}`", vector_value.len());
-								std::process::exit(1);
+								self.raise("IndexError", format!("Invalid index to a vector with length `{}`", vector_value.len()));
 							} else {
 								((vector_value.len() as i32) + value) as usize
 							}
@@ -1616,23 +4427,68 @@ impl Executor {
 						}
 					},
 					_ => {
-						println!("RTE: You can only index a Vector with Integer only.");
-						std::process::exit(1);
+						self.raise("TypeError", String::from("You can only index a Vector with Integer only."));
 					}
 				};
+				if index_value >= vector_value.len() {
+					self.raise("IndexError", format!("Index `{index_value}` is out of range for a vector with length `{}`", vector_value.len()));
+				}
 				
 				vector_value[index_value].clone()
 			},
-			ASTNode::ID{ name } => {
+			ASTNode::ID{name, line} => {
+				self.line = line;
+
+				if self.find_variable(&name).is_none() {
+					// Named functions are values too -> let f = add;
+					let function = self.functions.last().and_then(|funcs| funcs.get(&name)).cloned();
+
+					if let Some((parameters, block)) = function {
+						let environment = HashMap::new();
+						let function = FunctionValue { name, parameters, block, environment };
+						return self.lazy2_value(LazyResult::Function(Rc::new(function)));
+					}
+				}
 				let rn_lazy_val = self.get_variable_value(&name).unwrap();
-				
+				self.count_read(&name, line);
+
 				let rn_value: Value;
 				match rn_lazy_val {
-					LazyResult::Expression { expr } => {
-						//We have an expression to execute
+					LazyResult::Expression { expr, environment, memo, declared, .. } => {
+						// Call-by-need -> only the first read evaluates the expression
+						let cached = memo.borrow().clone();
+						if let Some(value) = cached {
+							return value;
+						}
 						let expr: &ASTNode = &(*expr.clone());
 
+						// Evaluated against its captured bindings (and globals) like a call with no arguments.
+						// Emptying the environment afterwards releases the bindings it kept alive,
+						// until then an error caught by a `try` leaves the binding as it was
+						let captured = environment.borrow().clone();
+						let started = Instant::now();
+						self.forcing.push((name.to_string(), declared, line));
+						self.line = declared;
+						self.enter_call(&name);
+						self.set_scope(captured);
+						self.frames.push(self.scopes.len());
+
 						rn_value = self.evaluate(expr.clone());
+
+						self.frames.pop();
+						self.clean_scope();
+						self.call_depth -= 1;
+						self.forcing.pop();
+						self.line = line;
+						environment.borrow_mut().clear();
+
+						if let Some(stat) = self.lazy_stats.as_mut().and_then(|stats| stats.get_mut(&(name.to_string(), declared))) {
+							stat.forced += 1;
+							stat.time += started.elapsed();
+						}
+
+						*memo.borrow_mut() = Some(rn_value.clone());
+						self.replace_thunk(&name, &memo, rn_value.clone());
 					},
 					_ => {
 						rn_value = self.lazy2_value(rn_lazy_val);
@@ -1640,8 +4496,68 @@ impl Executor {
 				}
 				return rn_value;
 			},
+			ASTNode::PropertyAccess{object, property} => {
+				let value = self.evaluate((*object).clone());
+				let ASTNode::ID{name: ref attribute, ..} = *property else {
+					println!("Invalid attribute name");
+					self.fail();
+				};
+
+				if let Some(ref instance) = value.struct_value {
+					let instance = instance.borrow();
+					for (name, field) in &instance.fields {
+						if name == attribute {
+							return field.clone();
+						}
+					}
+				}
+				let lazy_value = self.value2_lazy(value);
+				self.raise("AttributeError", format!("Type `{}` has no attribute `{attribute}`", self.type_name(&lazy_value)));
+			},
+			ASTNode::Lambda{parameters, block, ..} => {
+				let environment = self.capture_environment(&parameters, &block);
+				let function = FunctionValue { name: String::from("<lambda>"), parameters, block, environment };
+
+				return self.lazy2_value(LazyResult::Function(Rc::new(function)));
+			},
+			ASTNode::Constant{value} => value,
+			ASTNode::Try{body, catches, finally} => {
+				let value = match self.try_execution(body, catches, finally) {
+					LazyResult::Null => LazyResult::None,
+					value => value
+				};
+				return self.lazy2_value(value);
+			},
+			ASTNode::Loop{body, label} => {
+				// A plain `break` -> None
+				let value = match self.loop_execution(body, label) {
+					LazyResult::Null => LazyResult::None,
+					value => value
+				};
+				return self.lazy2_value(value);
+			},
+			ASTNode::If{condition, if_block, else_block} => {
+				// No branch taken, or one ending in a statement -> None
+				let value = match self.if_execution((*condition).clone(), if_block, else_block) {
+					LazyResult::Null => LazyResult::None,
+					value => value
+				};
+				return self.lazy2_value(value);
+			},
+			ASTNode::Keyword{ref name, ..} => {
+				self.raise("TypeError", format!("Unexpected keyword argument `{name}`"));
+			},
+			ASTNode::Spread{..} => {
+				self.raise("SyntaxError", String::from("`...` can only be used in calls and Vector literals"));
+			},
 			ASTNode::FunctionCall{ref name, args} => {
+				if let ASTNode::ID{line, ..} = **name {
+					self.line = line;
+				}
+				// Back on the caller's line once the call returns
+				let line = self.line;
 				let var = self.func_call(&name, args);
+				self.line = line;
 
 				return self.lazy2_value (var);
 			},
@@ -1651,40 +4567,178 @@ impl Executor {
 			ASTNode::UnaryOperation {ref operand, ref operator} => {
 				return self.evaluate_unary_expression(operator.to_string(), operand.clone());
 			},
-			ASTNode::Flow {ref value} => {
-				match value.as_str() {
-					"break" => self.break_loop = true,
-					_ => self.continue_loop = true,
-				}
+			ASTNode::Flow {ref value, ref label, result} => {
+				let result = result.map(|result| self.evaluate((*result).clone()));
+				self.signal = match value.as_str() {
+					"break" => Some(Signal::Break(label.clone(), result)),
+					_ => Some(Signal::Continue(label.clone())),
+				};
 				return self.lazy2_value(LazyResult::Null);
 			}
 			_ => {
 				println!("Invalid expression at {expression:#?}");
-				std::process::exit(1);
+				self.fail();
 			}
 		}
 	}
 
 	fn get_variable_value(&mut self, name: &String)-> Option<LazyResult> {
+		match self.find_variable(name) {
+			Some(value) => value,
+			None => {
+				self.raise("NameError", format!("`{name}` is not defined"));
+			}
+		}
+	}
+
+	fn find_variable(&self, name: &str) -> Option<Option<LazyResult>> {
+		// Lexical lookup -> the blocks of the running call, then globals.
+		// A caller's locals are never visible to the function it calls
+		let scope = self.scope_of(name)?;
+		let scope = match scope {
+			None => &self.current_scope,
+			Some(i) => &self.scopes[i]
+		};
+		match scope.get(name).unwrap() {
+			Some(LazyResult::Shared(cell)) => Some(cell.borrow().clone()),
+			binding => Some(binding.clone())
+		}
+	}
+
+	fn scope_of(&self, name: &str) -> Option<Option<usize>> {
+		// Where `name` is declared -> Some(None) for the current scope, Some(Some(i)) for self.scopes[i].
+		// scopes[0] is the initial empty scope, scopes[1] the globals once a scope was set
 		if self.current_scope.contains_key(name) {
-			return self.current_scope.get(name).unwrap().clone();
+			return Some(None);
 		}
-		self.scopes.reverse();
-		
-		for scope in &self.scopes.clone() {
-			if scope.contains_key(name) {
-				self.scopes.reverse();
-				return scope.get(name).unwrap().clone();
+		let start = self.frames.last().copied().unwrap_or(0);
+
+		for i in (start..self.scopes.len()).rev() {
+			if self.scopes[i].contains_key(name) {
+				return Some(Some(i));
+			}
+		}
+		if start > 1 && self.scopes[1].contains_key(name) {
+			return Some(Some(1));
+		}
+		None
+	}
+
+	fn assign_variable(&mut self, name: &str, value: LazyResult) {
+		// Updates the binding `name` resolves to, wherever it was declared
+		let scope = match self.scope_of(name) {
+			Some(None) => &mut self.current_scope,
+			Some(Some(i)) => &mut self.scopes[i],
+			None => {
+				self.raise("NameError", format!("Cannot assign to `{name}`, it was never declared"));
+			}
+		};
+		match scope.get(name) {
+			Some(Some(LazyResult::Shared(cell))) => *cell.borrow_mut() = Some(value),
+			_ => { scope.insert(name.to_string(), Some(value)); }
+		}
+	}
+
+	fn capture_names(&self, expression: &ASTNode) -> HashMap<String, Option<LazyResult>> {
+		// Copies the current binding of every name the expression mentions
+		let mut names: Vec<String> = vec![];
+		referenced_names(expression, &mut names);
+
+		let mut environment: HashMap<String, Option<LazyResult>> = HashMap::new();
+		for name in names {
+			if let Some(value) = self.find_variable(&name) {
+				environment.insert(name, value);
+			}
+		}
+		environment
+	}
+
+	fn replace_thunk(&mut self, name: &str, memo: &Rc<RefCell<Option<Value>>>, value: Value) {
+		// The binding holds the value from now on, unless it was re-bound while forcing
+		let lazy_value = self.value2_lazy(value);
+		let scope = match self.scope_of(name) {
+			Some(None) => &mut self.current_scope,
+			Some(Some(i)) => &mut self.scopes[i],
+			None => return
+		};
+
+		match scope.get(name) {
+			Some(Some(LazyResult::Expression{memo: ref current, ..})) if Rc::ptr_eq(current, memo) => {
+				scope.insert(name.to_string(), Some(lazy_value));
+			},
+			Some(Some(LazyResult::Shared(cell))) => {
+				let mut binding = cell.borrow_mut();
+				if matches!(*binding, Some(LazyResult::Expression{memo: ref current, ..}) if Rc::ptr_eq(current, memo)) {
+					*binding = Some(lazy_value);
+				}
+			},
+			_ => ()
+		}
+	}
+
+	fn assignment(&mut self, name: &Rc<ASTNode>, value: ASTNode) -> LazyResult {
+		// x = value; -> evaluated now, the old value of x is gone afterwards
+		let ASTNode::ID{ref name, line} = **name else {
+			self.raise("RuntimeError", String::from("Invalid assignment target"));
+		};
+		self.line = line;
+		let value = self.evaluate(value);
+		let lazy_value = self.value2_lazy(value);
+		self.assign_variable(name, lazy_value);
+
+		return LazyResult::Null;
+	}
+
+	fn capture_environment(&mut self, parameters: &(Option<Vec<ASTNode>>, Option<Vec<ASTNode>>), block: &[ASTNode]) -> HashMap<String, Option<LazyResult>> {
+		// Closures share the bindings of the running call (or top-level blocks) they mention, globals are shared anyway.
+		// Each captured binding is moved into a cell that stays in its scope, so later reads
+		// and assignments on either side see each other
+		let mut environment: HashMap<String, Option<LazyResult>> = HashMap::new();
+
+		if self.scopes.len() < 2 {
+			return environment;
+		}
+		let mut names: Vec<String> = vec![];
+		for node in parameters.0.iter().flatten().chain(parameters.1.iter().flatten()).chain(block.iter()) {
+			referenced_names(node, &mut names);
+		}
+		let start = self.frames.last().copied().unwrap_or(0).max(2);
+
+		for name in names {
+			let scope = match self.scope_of(&name) {
+				Some(None) => &mut self.current_scope,
+				Some(Some(i)) if i >= start => &mut self.scopes[i],
+				_ => continue
+			};
+			let binding = scope.get_mut(&name).unwrap();
+
+			if !matches!(binding, Some(LazyResult::Shared(..))) {
+				let cell = Rc::new(RefCell::new(binding.take()));
+				*binding = Some(LazyResult::Shared(cell));
 			}
+			environment.insert(name, binding.clone());
 		}
-		println!("RTE: Variable `{name}` not defined");
-		std::process::exit(1);
+		environment
 	}
 
 	fn evaluate_binary_expression(&mut self, left:Rc<ASTNode>, operation:String, right:Rc<ASTNode>) -> Value {
 		let value = self.evaluate((*left).clone());
 		let lazy_left_value = self.value2_lazy(value);
 
+		if operation == "&&" || operation == "||" {
+			// Short-circuit: the right operand is only evaluated if it decides the result
+			let left_truth = self.truthy(&lazy_left_value);
+
+			if (operation == "&&" && !left_truth) || (operation == "||" && left_truth) {
+				return self.lazy2_value(LazyResult::Bool(left_truth));
+			}
+			let value = self.evaluate((*right).clone());
+			let lazy_right_value = self.value2_lazy(value);
+			let right_truth = self.truthy(&lazy_right_value);
+
+			return self.lazy2_value(LazyResult::Bool(right_truth));
+		}
+
 		let value = self.evaluate((*right).clone());
 		let lazy_right_value = self.value2_lazy(value);
 
@@ -1700,24 +4754,19 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Int( (ll_value as f64 + lr_value) as i32 ));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int + bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int + bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int + Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int + Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int + None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int + None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int + Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int + Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int + Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -1730,120 +4779,95 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Float(ll_value + lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float + bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float + bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float + Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float + Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float + None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float + None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float + Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float + Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float + Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool + Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool + Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool + bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool + Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool + None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool + Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool + Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(mut ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str + Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str + Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str + bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + bool`"));
 							},
 							LazyResult::Str(lr_value) => {
 								ll_value.push_str(&lr_value);
 								return self.lazy2_value(LazyResult::Str(ll_value));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str + None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str + Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Str + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str + Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None + Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None + Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None + bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None + Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None + None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None + Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None + Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -1874,14 +4898,12 @@ impl Executor {
 								return self.lazy2_value(LazyResult::List(ll_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector + Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector + Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} + Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} + Type"));
 					}
 				}	
 			}, 
@@ -1897,24 +4919,19 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Int((ll_value as f64 - lr_value) as i32));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int - bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int - bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int - Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -1927,158 +4944,124 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Float(ll_value - lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float - bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float - bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float - Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool - Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool - Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool - bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool - Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str - Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str - Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str - bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Str - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str - Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None - Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None - Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None - bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None - Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector - Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector - Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector - Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector - Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector - None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector - Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector - Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector - Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} - Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} - Type"));
 					}
 				}	
 			}, 
@@ -2088,30 +5071,28 @@ impl Executor {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
+								if lr_value == 0 {
+									self.raise("ZeroDivisionError", String::from("Division by zero"));
+								}
 								return self.lazy2_value(LazyResult::Int(ll_value / lr_value));
 							},
 							LazyResult::Float(lr_value) => {
 								return self.lazy2_value(LazyResult::Int((ll_value as f64 / lr_value) as i32));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int / bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int / bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int / Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -2124,158 +5105,124 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Float(ll_value / lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float / bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float / bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float / Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool / Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool / Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool / bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool / Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str / Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str / Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str / bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Str / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str / Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None / Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None / Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None / bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None / Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector / Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector / Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector / Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector / Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector / None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector / Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector / Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector / Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} / Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} / Type"));
 					}
 				}	
 			}, 
@@ -2291,8 +5238,7 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Int((ll_value as f64 * lr_value) as i32));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int * bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int * bool`"));
 							},
 							LazyResult::Str(lr_value) => {
 								let mut result = String::new();
@@ -2303,16 +5249,13 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Str(result));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int * Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int * Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -2325,56 +5268,44 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Float(ll_value * lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float * bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float * bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float * Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float * Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float * Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float * Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool * Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool * Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool * bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool * Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool * Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -2389,98 +5320,77 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Str(result));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str * Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str * bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str * Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str * Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None * Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None * Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None * bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None * Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None * Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector * Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector * Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector * Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector * Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector * None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector * Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector * Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector * Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} * Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} * Type"));
 					}
 				}	
 			}, 
@@ -2496,24 +5406,19 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Int(f64::powf(ll_value as f64, lr_value) as i32));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int ^ bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int ^ bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int ^ Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -2526,158 +5431,124 @@ impl Executor {
 								return self.lazy2_value(LazyResult::Float(f64::powf(ll_value, lr_value as f64)));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float ^ bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float ^ bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float ^ Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool ^ Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool ^ Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool ^ bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str ^ Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str ^ Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str ^ bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None ^ Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None ^ Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None ^ bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector ^ Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector ^ Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector ^ Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector ^ Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector ^ None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector ^ Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector ^ Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector ^ Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} ^ Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} ^ Type"));
 					}
 				}	
 			}, 
@@ -2687,196 +5558,158 @@ impl Executor {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
+								if lr_value == 0 {
+									self.raise("ZeroDivisionError", String::from("Modulo by zero"));
+								}
 								return self.lazy2_value(LazyResult::Int(ll_value % lr_value));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Int % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int % bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Float % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % Float`"));
 							},
 							LazyResult::Float(lr_value) => {
 								return self.lazy2_value(LazyResult::Float(ll_value % lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float % bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool % Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `bool % bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str % Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str % bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None % Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None % bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(..) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector % Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector % Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector % Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector % Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector % None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector % Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector % Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector % Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} % Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} % Type"));
 					}
 				}	
 			}, 
@@ -2886,1293 +5719,732 @@ impl Executor {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
-							},
-							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value as f64 > lr_value));
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int > bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int > Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Int > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int > Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `Int > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Float(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value as f64));
-							},
-							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float > bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float > Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Float > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float > Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `Float > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Bool(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool > Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool > Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool > Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `bool > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool > Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `bool > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Str(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str > Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str > Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str > bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() > lr_value.as_str()));
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Str > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str > Vector`");
-								std::process::exit(1);
-							},
-							_ => {
-								println!("RTE: No implementation for `Str > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::None => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None > Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None > Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None > bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None > Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `None > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None > Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `None > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::List(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector > Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector > Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector > Bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector > Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Vector > None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
-							}
-							_ => {
-								println!("RTE: No implementation for `Vector > Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} > Type");
-						std::process::exit(1);
-					}
-				}	
-			}, 
-			
-			">=" => {
-				match lazy_left_value {
-					LazyResult::Int(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
-							},
-							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value as f64 >= lr_value));
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int >= bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int >= Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Int >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int >= Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `Int >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Float(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value as f64));
-							},
-							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float >= bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float >= Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Float >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float >= Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `Float >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Bool(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool >= Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool >= Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool >= Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `bool >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool >= Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `bool >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::Str(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str >= Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str >= Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str >= bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() >= lr_value.as_str()));
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Str >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str >= Vector`");
-								std::process::exit(1);
-							},
-							_ => {
-								println!("RTE: No implementation for `Str >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::None => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None >= Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None >= Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None >= bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None >= Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `None >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None >= Vector`");
-								std::process::exit(1);
-							}
-							_ => {
-								println!("RTE: No implementation for `None >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					LazyResult::List(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector >= Int`");
-								std::process::exit(1);
-							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector >= Float`");
-								std::process::exit(1);
-							},
-							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector >= Bool`");
-								std::process::exit(1);
-							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector >= Str`");
-								std::process::exit(1);
-							},
-							LazyResult::None => {
-								println!("RTE: No implementation for `Vector >= None`");
-								std::process::exit(1);
-							},
-							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
-							}
-							_ => {
-								println!("RTE: No implementation for `Vector >= Type`.\nMay be caused by int.");
-								std::process::exit(1);
-							}
-						}
-					},
-					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} >= Type");
-						std::process::exit(1);
-					}
-				}	
-			}, 
-			
-			"<" => {
-				match lazy_left_value {
-					LazyResult::Int(ll_value) => {
-						match lazy_right_value {
-							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool((ll_value as f64) < lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value as f64 > lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int < bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int > bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int < Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int > Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int > None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int < Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int > Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value as f64));
+								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value as f64));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float < bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float > bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float < Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float > Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float > None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float < Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float > Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool < Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool < Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool < Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool < Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str < Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str < Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str < bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > bool`"));
 							},
 							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() < lr_value.as_str()));
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() > lr_value.as_str()));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str < Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None < Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None < Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None < bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None < Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None < Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector < Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector < Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector < Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector < Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector < None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > None`"));
 							},
 							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value > lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector < Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector > Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} < Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} > Type"));
 					}
 				}	
 			}, 
 			
-			"<=" => {
+			">=" => {
 				match lazy_left_value {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value as f64 <= lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value as f64 >= lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int <= bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int >= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int <= Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int >= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Int <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int >= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int <= Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int >= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value as f64));
+								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value as f64));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float <= bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float >= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float <= Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float >= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Float <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float >= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float <= Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float >= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool <= Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool <= Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool <= Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `bool <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool <= Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str <= Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str <= Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str <= bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= bool`"));
 							},
 							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() <= lr_value.as_str()));
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() >= lr_value.as_str()));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Str <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str <= Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None <= Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None <= Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None <= bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None <= Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None <= Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector <= Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector <= Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector <= Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector <= Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector <= None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= None`"));
 							},
 							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value >= lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector <= Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector >= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} <= Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} >= Type"));
 					}
 				}	
 			}, 
 			
-			"!=" => {
+			"<" => {
 				match lazy_left_value {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value as f64 != lr_value));
+								return self.lazy2_value(LazyResult::Bool((ll_value as f64) < lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int != bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int < bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int != Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int < Str`"));
 							},
-							LazyResult::None => { // 0 = None removed this assumption that 0 = None
-								println!("RTE: No implementation for `Int != None`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Int < None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int < Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int < Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value as f64));
+								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value as f64));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float != bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float < bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float != Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float < Str`"));
 							},
-							LazyResult::None => { //None = 0.0 removed it
-								println!("RTE: No implementation for `Float != Str`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Float < None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float < Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float < Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool != Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool < Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool != Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool < Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool != Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool < Str`"));
 							},
-							LazyResult::None => { // false = None
-								println!("RTE: No implementation for `bool != Str`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `bool < None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool < Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool < Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str != Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str < Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str != Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str < Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str != bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str < bool`"));
 							},
 							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() != lr_value.as_str()));
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() < lr_value.as_str()));
 							},
-							LazyResult::None => { // None = "" removed this assumption
-								println!("RTE: No implementation for `Int != Str`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Str < None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str < Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str < Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None != Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None != Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None != Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None != None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None != Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None < Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector != Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector != Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector != Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector != Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector != None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < None`"));
 							},
 							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value < lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector != Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector < Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} != Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} < Type"));
 					}
 				}	
 			}, 
 			
-			"==" => {
+			"<=" => {
 				match lazy_left_value {
 					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value as f64 == lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value as f64 <= lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int == bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int <= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int == Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int <= Str`"));
 							},
-							LazyResult::None => { // 0 = None removed this assumption that 0 = None
-								println!("RTE: No implementation for `Int == None`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Int <= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int <= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value as f64));
+								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value as f64));
 							},
 							LazyResult::Float(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float == bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float <= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float == Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float <= Str`"));
 							},
-							LazyResult::None => { //None = 0.0 removed it
-								println!("RTE: No implementation for `Float == Str`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Float <= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float <= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool == Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool <= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool == Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool <= Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool == Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool <= Str`"));
 							},
-							LazyResult::None => { // false = None
-								println!("RTE: No implementation for `bool == None`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `bool <= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool <= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str == Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str <= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str == Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str <= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str == bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str <= bool`"));
 							},
 							LazyResult::Str(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() == lr_value.as_str()));
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() <= lr_value.as_str()));
 							},
-							LazyResult::None => { // None = "" removed this assumption
-								println!("RTE: No implementation for `Int == Str`");
-								std::process::exit(1);
+							LazyResult::None => {
+								self.raise("TypeError", String::from("No implementation for `Str <= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str <= Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None == Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None == Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None == Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None == None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None == Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector == Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector == Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector == Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector == Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector == None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= None`"));
 							},
 							LazyResult::List(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value <= lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector == Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector <= Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} == Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} <= Type"));
 					}
 				}	
 			}, 
 			
-			"&&" => {
+			"!=" => {
 				match lazy_left_value {
-					LazyResult::Int(..) => {
+					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Int & bool`");
-								std::process::exit(1);
+							LazyResult::Int(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
 							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Int & bool`");
-								std::process::exit(1);
+							LazyResult::Float(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value as f64 != lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int & bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int != bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int & Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int != Str`"));
 							},
-							LazyResult::None => { // 
-								println!("RTE: No implementation for `Int & None`");
-								std::process::exit(1);
+							LazyResult::None => { // 0 = None removed this assumption that 0 = None
+								self.raise("TypeError", String::from("No implementation for `Int != None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int != Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int != Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::Float(..) => {
+					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Float & Int`");
-								std::process::exit(1);
+							LazyResult::Int(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value as f64));
 							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Float & Float`");
-								std::process::exit(1);
+							LazyResult::Float(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float & bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float != bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float & Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float != Str`"));
 							},
-							LazyResult::None => { //None = 0.0
-								println!("RTE: No implementation for `Float & Str`");
-								std::process::exit(1);
+							LazyResult::None => { //None = 0.0 removed it
+								self.raise("TypeError", String::from("No implementation for `Float != Str`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float != Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float != Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool & Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool & Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value && lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool & Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Str`"));
 							},
 							LazyResult::None => { // false = None
-								println!("RTE: No implementation for `bool & None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Str`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool != Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::Str(..) => {
+					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str & Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str != Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str & Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str != Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str & bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str != bool`"));
 							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str & Str`");
-								std::process::exit(1);
+							LazyResult::Str(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() != lr_value.as_str()));
 							},
-							LazyResult::None => { 
-								println!("RTE: No implementation for `Str & None`");
-								std::process::exit(1);
+							LazyResult::None => { // None = "" removed this assumption
+								self.raise("TypeError", String::from("No implementation for `Int != Str`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str != Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str != Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -4180,202 +6452,158 @@ impl Executor {
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None & Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None & Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None & Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Vector`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None & None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None & Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None != Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::List(..) => {
+					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector & Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector != Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector & Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector != Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector & Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector != Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector & Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector != Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector & None`");
-								std::process::exit(1);
-
+								self.raise("TypeError", String::from("No implementation for `Vector != None`"));
 							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector & Vector`");
-								std::process::exit(1);
-
+							LazyResult::List(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value != lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector & Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector != Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} & Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} != Type"));
 					}
 				}	
 			}, 
 			
-			"||" => {
+			"==" => {
 				match lazy_left_value {
-					LazyResult::Int(..) => {
+					LazyResult::Int(ll_value) => {
 						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Int | bool`");
-								std::process::exit(1);
+							LazyResult::Int(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
 							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Int | bool`");
-								std::process::exit(1);
+							LazyResult::Float(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value as f64 == lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Int | bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int == bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Int | Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int == Str`"));
 							},
-							LazyResult::None => { // 
-								println!("RTE: No implementation for `Int | None`");
-								std::process::exit(1);
+							LazyResult::None => { // 0 = None removed this assumption that 0 = None
+								self.raise("TypeError", String::from("No implementation for `Int == None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Int | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int == Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Int | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Int == Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::Float(..) => {
+					LazyResult::Float(ll_value) => {
 						match lazy_right_value {
-							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Float | Int`");
-								std::process::exit(1);
+							LazyResult::Int(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value as f64));
 							},
-							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Float | Float`");
-								std::process::exit(1);
+							LazyResult::Float(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Float | bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float == bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Float | Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float == Str`"));
 							},
-							LazyResult::None => { //None = 0.0
-								println!("RTE: No implementation for `Float | Str`");
-								std::process::exit(1);
+							LazyResult::None => { //None = 0.0 removed it
+								self.raise("TypeError", String::from("No implementation for `Float == Str`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Float | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float == Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `Float | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Float == Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					LazyResult::Bool(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `bool | Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `bool | Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == Float`"));
 							},
 							LazyResult::Bool(lr_value) => {
-								return self.lazy2_value(LazyResult::Bool(ll_value || lr_value));
+								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `bool | Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == Str`"));
 							},
 							LazyResult::None => { // false = None
-								println!("RTE: No implementation for `bool | None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `bool | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `bool | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `bool == Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::Str(..) => {
+					LazyResult::Str(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Str | Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str == Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Str | Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str == Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Str | bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str == bool`"));
 							},
-							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Str | Str`");
-								std::process::exit(1);
+							LazyResult::Str(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value.as_str() == lr_value.as_str()));
 							},
-							LazyResult::None => { 
-								println!("RTE: No implementation for `Str | None`");
-								std::process::exit(1);
+							LazyResult::None => { // None = "" removed this assumption
+								self.raise("TypeError", String::from("No implementation for `Int == Str`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Str | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str == Vector`"));
 							},
 							_ => {
-								println!("RTE: No implementation for `Str | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Str == Type`.\nMay be caused by int."));
 							}
 						}
 					},
@@ -4383,239 +6611,196 @@ impl Executor {
 					LazyResult::None => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `None | Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `None | Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `None | Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `None | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Vector`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `None | None`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == None`"));
 							},
 							LazyResult::List(..) => {
-								println!("RTE: No implementation for `None | Vector`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Vector`"));
 							}
 							_ => {
-								println!("RTE: No implementation for `None | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `None == Type`.\nMay be caused by int."));
 							}
 						}
 					},
-					LazyResult::List(..) => {
+					LazyResult::List(ll_value) => {
 						match lazy_right_value {
 							LazyResult::Int(..) => {
-								println!("RTE: No implementation for `Vector | Int`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector == Int`"));
 							},
 							LazyResult::Float(..) => {
-								println!("RTE: No implementation for `Vector | Float`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector == Float`"));
 							},
 							LazyResult::Bool(..) => {
-								println!("RTE: No implementation for `Vector | Bool`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector == Bool`"));
 							},
 							LazyResult::Str(..) => {
-								println!("RTE: No implementation for `Vector | Str`");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector == Str`"));
 							},
 							LazyResult::None => {
-								println!("RTE: No implementation for `Vector | None`");
-								std::process::exit(1);
-
+								self.raise("TypeError", String::from("No implementation for `Vector == None`"));
 							},
-							LazyResult::List(..) => {
-								println!("RTE: No implementation for `Vector | Vector`");
-								std::process::exit(1);
-
+							LazyResult::List(lr_value) => {
+								return self.lazy2_value(LazyResult::Bool(ll_value == lr_value));
 							}
 							_ => {
-								println!("RTE: No implementation for `Vector | Type`.\nMay be caused by int.");
-								std::process::exit(1);
+								self.raise("TypeError", String::from("No implementation for `Vector == Type`.\nMay be caused by int."));
 							}
 						}
 					},
 					_ => {
-						println!("RTE: No implemention for {lazy_left_value:?} | Type");
-						std::process::exit(1);
+						self.raise("TypeError", format!("No implemention for {lazy_left_value:?} == Type"));
 					}
 				}	
 			}, 
 			
+			"&" | "|" | "~" | "<<" | ">>" => {
+				match (&lazy_left_value, &lazy_right_value) {
+					(LazyResult::Int(ll_value), LazyResult::Int(lr_value)) => {
+						let (ll_value, lr_value) = (*ll_value, *lr_value);
+						let result = match operation.as_str() {
+							"&" => ll_value & lr_value,
+							"|" => ll_value | lr_value,
+							"~" => ll_value ^ lr_value,
+							_ => {
+								if !(0..32).contains(&lr_value) {
+									self.raise("ValueError", format!("Shift amount `{lr_value}` is out of range for `Int {operation} Int`"));
+								}
+								if operation == "<<" { ll_value << lr_value } else { ll_value >> lr_value }
+							}
+						};
+						return self.lazy2_value(LazyResult::Int(result));
+					},
+					(LazyResult::Bool(ll_value), LazyResult::Bool(lr_value)) if !operation.starts_with(['<', '>']) => {
+						let result = match operation.as_str() {
+							"&" => *ll_value & *lr_value,
+							"|" => *ll_value | *lr_value,
+							_ => *ll_value ^ *lr_value
+						};
+						return self.lazy2_value(LazyResult::Bool(result));
+					},
+					_ => {
+						self.raise("TypeError", format!(
+							"No implementation for `{} {operation} {}`",
+							self.type_name(&lazy_left_value), self.type_name(&lazy_right_value)
+						));
+					}
+				}
+			},
+
 			_ => {
 				println!("(Int) Binary operator not Implemented {operation}");
-				std::process::exit(1);
+				self.fail();
 			}
 		}
 	}
+
+	fn type_name(&self, value: &LazyResult) -> String {
+		match value {
+			LazyResult::Int(..) => "Int",
+			LazyResult::Float(..) => "Float",
+			LazyResult::Str(..) => "Str",
+			LazyResult::Bool(..) => "Bool",
+			LazyResult::List(..) => "Vector",
+			LazyResult::Struct(instance) => return instance.borrow().name.clone(),
+			LazyResult::Function(..) => "Function",
+			LazyResult::Generator(..) => "Generator",
+			LazyResult::None | LazyResult::Null => "None",
+			LazyResult::Expression{..} => "Expression",
+			LazyResult::Shared(..) => "Shared",
+		}.to_string()
+	}
 	
 	fn evaluate_unary_expression(&mut self, operator: String, operand: Rc<ASTNode>) -> Value {
 		match operator.as_str() {
-			"!" => {
-				match *operand {
-					ASTNode::None => {
-						Value {
-							int_value: None,
-							float_value: None,
-							bool_value: Some(true),
-							string_value: None,
-							list_value: None,
-							value_type: 2_u8
-						}
-					},
-					ASTNode::Bool { value } => Value {
-						int_value: None,
-						float_value: None,
-						bool_value: Some(!value),
-						string_value: None,
-						list_value: None,
-						value_type: 2_u8
-					},
-					ASTNode::Integer { value } => Value {
-						int_value: Some(!value),
-						float_value: None,
-						bool_value: None,
-						string_value: None,
-						list_value: None,
-						value_type: 0_u8
-					},
-					ASTNode::Float {..} => {
-						println!("RTE: Cannot apply unary operator `!` to type Float");
-						std::process::exit(1);
+			"!" | "~" | "-" | "+" => {
+				// Evaluate the operand first, then dispatch on the runtime type
+				let value = self.evaluate((*operand).clone());
+				let lazy_value = self.value2_lazy(value);
+
+				match operator.as_str() {
+					"!" => {
+						// (![] == true )        -> True
+						let value = !self.truthy(&lazy_value);
+						self.lazy2_value(LazyResult::Bool(value))
 					},
-					ASTNode::Str{..} => {
-						println!("RTE: Cannot apply unary operator `!` to type Str");
-						std::process::exit(1);
-					},
-					ASTNode::ExpressionList {ref list} => {
-						if list.len() > 0 {
-							Value {
-								int_value: None,
-								float_value: None,
-								bool_value: Some(false),
-								string_value: None,
-								list_value: None,
-								value_type: 2_u8
-							}
-						} else { // (![] == true )        -> True
-							Value {
-								int_value: None,
-								float_value: None,
-								bool_value: Some(true),
-								string_value: None,
-								list_value: None,
-								value_type: 2_u8
+					"~" => {
+						match lazy_value {
+							LazyResult::Int(value) => self.lazy2_value(LazyResult::Int(!value)),
+							_ => {
+								self.raise("TypeError", format!("Cannot apply unary operator `~` to type {}", self.type_name(&lazy_value)));
 							}
 						}
 					},
 					_ => {
-						println!("(Int) Unary operator `!` not implemented for {operand:?}");
-						std::process::exit(1);
-					}
-				}
-			}, 
-			"-" => {
-				match *operand {
-					ASTNode::None => {
-						println!("RTE: Cannot apply unary operator `-` to type None.");
-						std::process::exit(1);
-					},
-					ASTNode::Bool { .. } =>  {
-						println!("RTE: Cannot apply unary operator `-` to type Bool ");
-						std::process::exit(1);
-					},
-					ASTNode::Integer { value } => Value {
-						int_value: Some(-value),
-						float_value: None,
-						bool_value: None,
-						string_value: None,
-						list_value: None,
-						value_type: 0_u8
-					},
-					ASTNode::Float { .. } => {
-						println!("RTE: Cannot apply unary operator `-` to type Float");
-						std::process::exit(1);
-					},
-					ASTNode::Str{..} => {
-						println!("RTE: Cannot apply unary operator `-` to type Str");
-						std::process::exit(1);
-					},
-					ASTNode::ExpressionList {..} => {
-						println!("RTE: Cannot apply unary operator `-` to type Vector");
-						std::process::exit(1);
-					},
-					_ => {
-						println!("(Int) Unary operator `-` not implemented for {operand:?}");
-						std::process::exit(1);
-					}
-				}
-			},
-			"++" => {
-				match *operand {
-					ASTNode::ID{ref name} => {						
-						let new_value: Option<LazyResult> = match
-							self.get_variable_value(name).unwrap()
-						{
-							LazyResult::Int(val) => {
-								Some(LazyResult::Int(val + 1))
+						match lazy_value {
+							LazyResult::Int(value) => {
+								let value = match operator.as_str() {
+									"-" => match value.checked_neg() {
+										Some(value) => value,
+										None => {
+											self.raise("OverflowError", format!("Cannot negate `{value}`, the result does not fit in an Int"));
+										}
+									},
+									_ => value
+								};
+								self.lazy2_value(LazyResult::Int(value))
+							},
+							LazyResult::Float(value) => {
+								let value = if operator == "-" { -value } else { value };
+								self.lazy2_value(LazyResult::Float(value))
 							},
-							LazyResult::Float(val) => {
-								Some(LazyResult::Float(val + 1.0))
+							LazyResult::None => {
+								self.raise("TypeError", format!("Cannot apply unary operator `{operator}` to type None."));
+							},
+							LazyResult::Bool(..) => {
+								self.raise("TypeError", format!("Cannot apply unary operator `{operator}` to type Bool"));
+							},
+							LazyResult::Str(..) => {
+								self.raise("TypeError", format!("Cannot apply unary operator `{operator}` to type Str"));
+							},
+							LazyResult::List(..) => {
+								self.raise("TypeError", format!("Cannot apply unary operator `{operator}` to type Vector"));
 							},
 							_ => {
-								println!("RTE: Wrong use of `++`");
-								std::process::exit(1);
+								println!("(Int) Unary operator `{operator}` not implemented for {lazy_value:?}");
+								self.fail();
 							}
-						};
-						self.current_scope.insert(name.to_string(), new_value.clone());
-						return self.lazy2_value(new_value.unwrap());
-					},
-					_ => {
-						println!("RTE: Wrong use of `++`");
-						std::process::exit(1);
+						}
 					}
 				}
 			},
-			"--" => {
-				match *operand {
-					ASTNode::ID{ref name} => {
-						let new_value: Option<LazyResult> = match
-							self.get_variable_value(name)
-						{
-							Some(LazyResult::Int(val)) => {
-								Some(LazyResult::Int(val - 1))
-							},
-							Some(LazyResult::Float(val)) => {
-								Some(LazyResult::Float(val - 1.0))
-							},
-							_ => {
-								println!("RTE: Wrong use of `--`");
-								std::process::exit(1);
-							}
-						};
-						self.current_scope.insert(name.to_string(), new_value.clone());
-						return self.lazy2_value(new_value.unwrap());
-					},
+			"++" | "--" => {
+				// Updates the variable the name resolves to, not a copy in the current scope
+				let ASTNode::ID{ref name, ..} = *operand else {
+					self.raise("TypeError", format!("Wrong use of `{operator}`"));
+				};
+				let value = self.evaluate((*operand).clone());
+				let step = if operator == "++" { 1 } else { -1 };
+
+				let new_value = match self.value2_lazy(value) {
+					LazyResult::Int(val) => LazyResult::Int(val + step),
+					LazyResult::Float(val) => LazyResult::Float(val + step as f64),
 					_ => {
-						println!("RTE: Wrong use of `--`");
-						std::process::exit(1);
+						self.raise("TypeError", format!("Wrong use of `{operator}`"));
 					}
-				}
+				};
+				self.assign_variable(name, new_value.clone());
+				return self.lazy2_value(new_value);
 			},
 			_ => {
 				println!("(Int) Unary operator not Implemented {operand:?}");
-				std::process::exit(1);
+				self.fail();
 			}
 		}
 	}
@@ -4628,14 +6813,53 @@ impl Executor {
 				bool_value: None,
 				string_value: None,
 				list_value: Some(val.clone()),
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 5_u8
 			},
+			LazyResult::Struct(val) => Value {
+				int_value: None,
+				float_value: None,
+				bool_value: None,
+				string_value: None,
+				list_value: None,
+				struct_value: Some(val),
+				func_value: None,
+				generator_value: None,
+				value_type: 6_u8
+			},
+			LazyResult::Function(val) => Value {
+				int_value: None,
+				float_value: None,
+				bool_value: None,
+				string_value: None,
+				list_value: None,
+				struct_value: None,
+				func_value: Some(val),
+				generator_value: None,
+				value_type: 7_u8
+			},
+			LazyResult::Generator(val) => Value {
+				int_value: None,
+				float_value: None,
+				bool_value: None,
+				string_value: None,
+				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: Some(val),
+				value_type: 8_u8
+			},
 			LazyResult::Null | LazyResult::None => Value {
 				int_value: None,
 				float_value: None,
 				bool_value: None,
 				string_value: None,
 				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 4_u8
 			},
 			LazyResult::Str(val) => Value {
@@ -4644,6 +6868,9 @@ impl Executor {
 				bool_value: None,
 				string_value: Some(val.clone()),
 				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 3_u8
 			},
 			LazyResult::Bool(val) => Value {
@@ -4652,6 +6879,9 @@ impl Executor {
 				bool_value: Some(val),
 				string_value: None,
 				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 2_u8
 			},
 			LazyResult::Float(val) => Value {
@@ -4660,6 +6890,9 @@ impl Executor {
 				bool_value: None,
 				string_value: None,
 				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 1_u8
 			},
 			LazyResult::Int(val) => Value {
@@ -4668,13 +6901,13 @@ impl Executor {
 				bool_value: None,
 				string_value: None,
 				list_value: None,
+				struct_value: None,
+				func_value: None,
+				generator_value: None,
 				value_type: 0_u8
 			},
 			_ => {
-				 println!(
-				 "RTE: Inconvertible lazy result. \nHint this may be an expresion conversion"
-				 );
-				 std::process::exit(1);
+				 self.raise("RuntimeError", String::from("Inconvertible lazy result. \nHint this may be an expresion conversion"));
 			 }
 		}		
 	}
@@ -4687,91 +6920,439 @@ impl Executor {
 			3 => LazyResult::Str(value.string_value.clone().unwrap()),
 			4 => LazyResult::None,
 			5 => LazyResult::List(value.list_value.clone().unwrap()),
+			6 => LazyResult::Struct(value.struct_value.clone().unwrap()),
+			7 => LazyResult::Function(value.func_value.clone().unwrap()),
+			8 => LazyResult::Generator(value.generator_value.clone().unwrap()),
 			127 => {
 				 println!("(Int)Undefined Value Type");
-				 std::process::exit(1);
+				 self.fail();
 			 },
 			_ => {
 				 println!("(Int)Invalid Value");
-				 std::process::exit(1);
+				 self.fail();
 			 }
 		}
 	}
 
-	fn execute_func(&mut self, func_name: String, args: Vec<ASTNode>) -> LazyResult {
-		if self.functions.len() == 0 {
-			println!("(Int)Functions are not found.\nIt may be caused by you or me. \nRestart the code(Int)");
-			std::process::exit(1);
+	fn named_call(&self, func_name: &str) -> Call {
+		if self.functions.len() == 0 {
+			println!("(Int)Functions are not found.\nIt may be caused by you or me. \nRestart the code(Int)");
+			self.fail();
+		}
+
+		let (parameters, block) = match self.functions.last().unwrap().get(func_name) {
+			Some(val) => val.clone(),
+			None =>  {
+				self.raise("NameError", format!("`{}` is not defined", &func_name));
+			}
+		};
+
+		Call { name: func_name.to_string(), parameters, block, receiver: None, environment: HashMap::new() }
+	}
+
+	fn invoke_func(&mut self, call: Call, args: Vec<ASTNode>) -> LazyResult {
+		// Calling a function that uses `yield` only binds its arguments
+		if contains_yield(&call.block) {
+			let args = self.evaluate_now(args);
+			return LazyResult::Generator(Rc::new(Generator::Call{ call, args }));
+		}
+		// Tail calls replace the finished call here instead of recursing
+		self.enter_call(&call.name);
+
+		let mut call = call;
+		let mut args = args;
+		loop {
+			let result = self.run_call(call, args);
+
+			match self.tail_call.take() {
+				Some((next_call, next_args)) if contains_yield(&next_call.block) => {
+					self.call_depth -= 1;
+					return LazyResult::Generator(Rc::new(Generator::Call{ call: next_call, args: next_args }));
+				},
+				Some((next_call, next_args)) => {
+					call = next_call;
+					args = next_args;
+				},
+				None => {
+					self.call_depth -= 1;
+					return result;
+				}
+			}
+		}
+	}
+
+	fn raise(&self, kind: &str, message: String) -> ! {
+		// A runtime error -> caught by the innermost `try`, or printed and the program ends
+		let error = MarError { kind: kind.to_string(), message, line: self.line };
+
+		if self.trying > 0 {
+			std::panic::panic_any(error);
+		}
+		println!("RTE: {error}");
+		self.fail();
+	}
+
+	fn rethrow(&mut self, error: MarError, value: Value) -> ! {
+		// An error caught only to run `finally` goes on to the next `try`
+		if self.trying > 0 {
+			self.thrown = Some(value);
+			std::panic::panic_any(error);
+		}
+		println!("RTE: {error}");
+		self.fail();
+	}
+
+	fn throw_statement(&mut self, value: ASTNode, line: usize) -> ! {
+		// throw "message" -> an `Error`, a thrown struct is caught as it is
+		let value = self.evaluate(value);
+		self.line = line;
+
+		match self.value2_lazy(value.clone()) {
+			LazyResult::Str(message) => self.raise("Error", message),
+			LazyResult::Struct(instance) => {
+				let instance = instance.borrow();
+				let message = instance.fields.iter()
+					.find(|(name, _)| name == "message")
+					.map_or(String::new(), |(_, message)| message.to_string());
+
+				self.thrown = Some(value.clone());
+				self.raise(&instance.name, message);
+			},
+			other => {
+				self.raise("TypeError", format!("Only a Str or a struct can be thrown, found `{}`", self.type_name(&other)));
+			}
+		}
+	}
+
+	fn try_execution(&mut self, body: Vec<ASTNode>, catches: Vec<ASTNode>, finally: Option<Vec<ASTNode>>) -> LazyResult {
+		// The value of a `try` is the value of its body, or of the catch clause that ran.
+		// While it runs `rn f()` in this function is a plain call, a tail call would leave the `try`
+		self.try_frames.push(self.frames.len());
+		let result = self.try_clauses(body, catches, finally);
+		self.try_frames.pop();
+
+		match result {
+			Ok(value) => value,
+			Err((error, value)) => self.rethrow(error, value)
+		}
+	}
+
+	fn try_clauses(&mut self, body: Vec<ASTNode>, catches: Vec<ASTNode>, finally: Option<Vec<ASTNode>>) -> Result<LazyResult, (MarError, Value)> {
+		let mut result = self.guarded(body, HashMap::new());
+
+		if let Err((ref error, ref value)) = result {
+			let clause = catches.into_iter().find(|clause| match clause {
+				ASTNode::Catch{kind: Some(kind), ..} => *kind == error.kind,
+				_ => true
+			});
+
+			if let Some(ASTNode::Catch{name, block, ..}) = clause {
+				let mut scope: HashMap<String, Option<LazyResult>> = HashMap::new();
+				if let Some(ASTNode::ID{name, ..}) = name.as_deref() {
+					scope.insert(name.to_string(), Some(self.value2_lazy(value.clone())));
+				}
+
+				// With a `finally` an error in the clause has to wait for it too
+				result = match finally {
+					Some(_) => self.guarded(block, scope),
+					None => {
+						self.set_scope(scope);
+						let value = self.block_value(block);
+						self.clean_scope();
+						Ok(value)
+					}
+				};
+			}
+		}
+
+		if let Some(finally) = finally {
+			// Runs whatever way the body was left. Its own `rn` / `break` replaces that way
+			let return_value = self.return_value.take();
+			let signal = self.signal.take();
+
+			self.set_scope(HashMap::new());
+			let _ = self.execute_block(finally);
+			self.clean_scope();
+
+			if self.return_value.is_some() || self.signal.is_some() {
+				return Ok(LazyResult::Null);
+			}
+			self.return_value = return_value;
+			self.signal = signal;
+		}
+		result
+	}
+
+	fn guarded(&mut self, block: Vec<ASTNode>, scope: HashMap<String, Option<LazyResult>>) -> Result<LazyResult, (MarError, Value)> {
+		// Runs a block as a scope, a runtime error in it unwinds back here
+		let (scopes, frames, consumers, forcing, try_frames, call_depth) = (
+			self.scopes.len(), self.frames.len(), self.consumers.len(), self.forcing.len(), self.try_frames.len(), self.call_depth
+		);
+
+		self.trying += 1;
+		let level = self.trying;
+		let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			self.set_scope(scope);
+			let value = self.block_value(block);
+			self.clean_scope();
+			value
+		}));
+
+		let payload = match outcome {
+			Ok(value) => {
+				self.trying -= 1;
+				return Ok(value);
+			},
+			// Raised by the code consuming a generator this `try` is in, it belongs to the consumer's `try`
+			Err(payload) if self.trying < level => std::panic::resume_unwind(payload),
+			Err(payload) => payload
+		};
+		self.trying = level - 1;
+		let error = match payload.downcast::<MarError>() {
+			Ok(error) => *error,
+			Err(payload) => std::panic::resume_unwind(payload)
+		};
+
+		// Back to the state the block started in, whatever calls and loops it was inside
+		while self.scopes.len() > scopes {
+			self.clean_scope();
 		}
+		self.frames.truncate(frames);
+		self.consumers.truncate(consumers);
+		self.forcing.truncate(forcing);
+		self.try_frames.truncate(try_frames);
+		self.call_depth = call_depth;
+		self.return_value = None;
+		self.signal = None;
+		self.tail_call = None;
+
+		let value = match self.thrown.take() {
+			Some(value) => value,
+			None => self.error_value(&error)
+		};
+		Err((error, value))
+	}
 
-		let funcs = self.functions.pop().unwrap();
-		let (params, block) = match funcs.get(&func_name) {
-			Some(val) => val,
-			None =>  {
-				println!("RTE: Function `{}` not found", &func_name);
-				self.functions.push(funcs);
-				std::process::exit(1);
+	fn error_value(&mut self, error: &MarError) -> Value {
+		// A runtime error as a Mar value -> IndexError { message, line }
+		let message = self.lazy2_value(LazyResult::Str(error.message.clone()));
+		let line = self.lazy2_value(LazyResult::Int(error.line as i32));
+		let instance = StructInstance {
+			name: error.kind.clone(),
+			fields: vec![(String::from("message"), message), (String::from("line"), line)]
+		};
+		self.lazy2_value(LazyResult::Struct(Rc::new(RefCell::new(instance))))
+	}
+
+	fn fail(&self) -> ! {
+		// Ends the program after a runtime error was printed.
+		// An error inside a lazy binding happens where it is read, so both places are shown
+		for (name, declared, read) in self.forcing.iter().rev() {
+			match read {
+				0 => println!("    while evaluating `{name}` declared at line {declared}"),
+				_ => println!("    while evaluating `{name}` declared at line {declared}, read at line {read}")
 			}
+		}
+		self.lazy_report();
+		std::process::exit(1);
+	}
+
+	fn enter_call(&mut self, name: &str) {
+		self.call_depth += 1;
+
+		if self.call_depth > self.max_call_depth {
+			self.raise("RecursionError", format!("Maximum call depth of {} exceeded in `{name}`", self.max_call_depth));
+		}
+	}
+
+	fn run_call(&mut self, call: Call, args: Vec<ASTNode>) -> LazyResult {
+		let Call { name: func_name, parameters: params, block, receiver, environment } = call;
+		let (input, output) = params;
+		let mut formal_params: Vec<ASTNode> = match input {
+			Some(p) => p.to_vec(),
+			None => vec![]
 		};
-		
-		//_ -> shows they are yet to be accepted in the program.
-		let (_input, _out_param) = params;
-		let formal_params: Vec<ASTNode> = match _input {
+		let output_params: Vec<ASTNode> = match output {
 			Some(p) => p.to_vec(),
 			None => vec![]
 		};
+		// Closures start from their captured environment
+		let mut new_scope: HashMap<String, Option<LazyResult>> = environment;
+
+		if let Some(receiver) = receiver {
+			// Methods bind the instance to their first parameter (self)
+			let self_param = if formal_params.is_empty() { None } else { Some(formal_params.remove(0)) };
+			let Some(ASTNode::ID{name, ..}) = self_param else {
+				self.raise("TypeError", format!("Method '{func_name}' must take `self` as its first parameter"));
+			};
+			new_scope.insert(name, Some(receiver));
+		}
+			
+		// Positional arguments first, then keyword arguments -> f(1, port = 9000)
+		// Both are evaluated here, in the caller's scope, with `...` spread
+		let mut positional: Vec<ASTNode> = vec![];
+		let mut keywords: Vec<(String, Rc<ASTNode>)> = vec![];
+
+		for arg in args {
+			match arg {
+				ASTNode::Keyword{name, value} => {
+					if keywords.iter().any(|(keyword, _)| *keyword == name) {
+						self.raise("TypeError", format!("Function '{func_name}' got multiple values for parameter `{name}`"));
+					}
+					keywords.push((name, value));
+				},
+				_ => {
+					if !keywords.is_empty() {
+						self.raise("TypeError", format!("Positional argument follows keyword argument in call to '{func_name}'"));
+					}
+					positional.push(arg);
+				}
+			}
+		}
+		let positional: Vec<Value> = self.evaluate_args(positional);
+
+		// Then the keyword arguments, in the order they were written
+		let mut keywords: Vec<(String, Value)> = keywords
+			.into_iter()
+			.map(|(name, value)| (name, self.evaluate((*value).clone())))
+			.collect();
 			
 		let p_len = formal_params.len();
+		let variadic = matches!(formal_params.last(), Some(ASTNode::Spread{..}));
 			
-		if p_len != args.len() {
-			let verb  = if args.len() > 1 {	"were" } else { "was" };
+		if p_len < positional.len() && !variadic {
+			let verb  = if positional.len() > 1 {	"were" } else { "was" };
 			let p = if p_len > 0 { ".." } else { "" };
 			
-			println!(
-				"RTE: Function '{}({p})' expects {} arguments, but {} {verb} provided",
-				&func_name, p_len, args.len()
-			);
-			std::process::exit(1);
+			self.raise("TypeError", format!(
+				"Function '{}({p})' expects {} arguments, but {} {verb} provided",
+				&func_name, p_len, positional.len()
+			));
 		}
-		
-		let mut new_scope: HashMap<String, Option<LazyResult>> = HashMap::new();
-		
-		if !args.is_empty() {
-			let mut param: &str;
-			let mut value: Value;
-			let mut lazy_argument: LazyResult;
-
-			for i in 0..args.len() {
-				param = match formal_params[i] {
-					ASTNode::ID{ref name} => {
-						name
+
+		let mut defaults: Vec<(String, Rc<ASTNode>)> = vec![];
+		let mut param: String;
+		let mut value: Value;
+		let mut lazy_argument: LazyResult;
+
+		for (i, formal_param) in formal_params.iter().enumerate() {
+			let default = match formal_param {
+				ASTNode::ID{ref name, ..} => {
+					param = name.to_string();
+					None
+				},
+				// Annotated or default parameter -> a: Int = 1
+				ASTNode::Var{ref name, ref value, ..} => match **name {
+					ASTNode::ID{ref name, ..} => {
+						param = name.to_string();
+						value.clone()
 					},
 					_ => {continue}
-				};
-				value = self.evaluate(args[i].clone());
-				lazy_argument = self.value2_lazy(value);
-				new_scope.insert(param.to_string(), Some(lazy_argument));
-				self.local_variables.push(param.to_string());
-			}
+				},
+				// Rest parameter -> ...parts collects the remaining positional arguments
+				ASTNode::Spread{ref value} => {
+					let ASTNode::ID{ref name, ..} = **value else {continue};
+					let rest: Vec<Value> = positional.iter().skip(i).cloned().collect();
+
+					new_scope.insert(name.to_string(), Some(LazyResult::List(rest)));
+					self.local_variables.push(name.to_string());
+					continue;
+				},
+				_ => {continue}
+			};
+			let keyword = keywords.iter().position(|(keyword, _)| *keyword == param);
+
+			value = if i < positional.len() {
+				if keyword.is_some() {
+					self.raise("TypeError", format!("Function '{func_name}' got multiple values for parameter `{param}`"));
+				}
+				positional[i].clone()
+			} else if let Some(keyword) = keyword {
+				keywords.remove(keyword).1
+			} else if let Some(default) = default {
+				// Defaults are evaluated in the function scope, once the arguments are bound
+				defaults.push((param, default));
+				continue;
+			} else {
+				self.raise("TypeError", format!("Function '{func_name}' is missing an argument for parameter `{param}`"));
+			};
+
+			lazy_argument = self.value2_lazy(value);
+			new_scope.insert(param.to_string(), Some(lazy_argument));
+			self.local_variables.push(param.to_string());
+		}
+
+		if let Some((name, _)) = keywords.first() {
+			self.raise("TypeError", format!("Function '{func_name}' got an unexpected keyword argument `{name}`"));
 		}
+		
 		// Change scope to new scope;
+		// the caller's scopes are out of reach from here on, only globals stay visible
 		self.set_scope(new_scope);
+		self.frames.push(self.scopes.len());
+
+		for (name, default) in defaults {
+			let value = self.evaluate((*default).clone());
+			let lazy_default = self.value2_lazy(value);
+			self.current_scope.insert(name, Some(lazy_default));
+		}
+
+		// Output parameters are declared in the function scope, after the inputs
+		// so that their defaults can use them -> fn f(a : total = a * 2)
+		let mut output_names: Vec<String> = vec![];
+		for param in output_params {
+			let (name, value) = match param {
+				ASTNode::ID{ref name, ..} => (name.to_string(), None),
+				ASTNode::Var{ref name, ref value, ..} => match **name {
+					ASTNode::ID{ref name, ..} => (name.to_string(), value.clone()),
+					_ => (String::new(), None)
+				},
+				_ => (String::new(), None)
+			};
+			if name.is_empty() {
+				self.raise("RuntimeError", format!("Invalid output parameter in function '{func_name}'"));
+			}
+
+			let value = match value {
+				Some(value) => {
+					let value = self.evaluate((*value).clone());
+					self.value2_lazy(value)
+				},
+				None => LazyResult::None
+			};
+			self.current_scope.insert(name.clone(), Some(value));
+			output_names.push(name);
+		}
 
-		let func_rn = self.execute_block(block.to_vec());
+		let func_rn = self.execute_block(block);
 		//func_rn -> true  = function returned sth
 		//           false = function didn't returned anyting
 
-		if func_rn {
+		let lazy_rn = if func_rn {
 			let lazy_rn = self.value2_lazy(self.return_value.clone().unwrap());
 			self.return_value = None;
-			self.clean_scope();
-			return lazy_rn;
-		}
+			lazy_rn
+		} else if !output_names.is_empty() {
+			// No `rn` -> the final values of the output parameters are returned,
+			// forced here before the function scope is dropped
+			let mut values: Vec<Value> = output_names
+				.into_iter()
+				.map(|name| self.evaluate(ASTNode::ID{ name, line: 0 }))
+				.collect();
+
+			if values.len() == 1 {
+				let value = values.remove(0);
+				self.value2_lazy(value)
+			} else {
+				LazyResult::List(values)
+			}
+		} else {
+			LazyResult::Null
+		};
+		self.frames.pop();
+		self.clean_scope();
 
-		//println!("Executing function: {func_name}...");
-		//println!("Scopes: {:?}", self.current_scope);
-		
-		return LazyResult::Null;
+		return lazy_rn;
 	}
 
 	fn set_scope(&mut self, scope: HashMap<String, Option<LazyResult>>) {
@@ -4796,30 +7377,86 @@ impl Executor {
 		//self.scopes.pop();
 	}
 
+	fn hoist_functions(&mut self, block: &[ASTNode]) {
+		// Local functions are bound before their block runs, so siblings can call each other.
+		// Each one is a cell, its declaration fills it again with every local declared before it
+		if self.scopes.len() < 2 {
+			return;
+		}
+		let functions: Vec<&ASTNode> = block.iter().filter(|statement| matches!(statement, ASTNode::Function{..})).collect();
+
+		for function in &functions {
+			if let ASTNode::Function{name, ..} = function {
+				if let ASTNode::ID{name, ..} = &**name {
+					self.current_scope.insert(name.to_string(), Some(LazyResult::Shared(Rc::new(RefCell::new(None)))));
+				}
+			}
+		}
+		for function in functions {
+			if let ASTNode::Function{name, parameters, block, ..} = function {
+				self.func_declaration(name.clone(), parameters.clone(), block.clone());
+			}
+		}
+	}
+
+	fn block_value(&mut self, block: Vec<ASTNode>) -> LazyResult {
+		// Like execute_block, but keeps what the last statement evaluated to
+		let mut result = LazyResult::Null;
+		self.hoist_functions(&block);
+
+		for statement in block {
+			result = self.execute_statement(statement);
+			if !self.return_value.is_none() || self.signal.is_some() {
+				return LazyResult::Null;
+			}
+		}
+		result
+	}
+
 	fn execute_block(&mut self, block: Vec<ASTNode>) -> bool {
 		//let mut rn_list: Value = vec![];
+		self.hoist_functions(&block);
+
 		for statement in block {
 			// We have ignored that a statement can return a value
 			let _ = self.execute_statement(statement);
 			if !self.return_value.is_none() {
 				return true;
 			}
+			// `break` / `continue` skip the rest of the block
+			if self.signal.is_some() {
+				return false;
+			}
 		}
 		return false;
 	}
 
 	fn func_declaration(&mut self, name: Rc<ASTNode>, parameters: (Option<Vec<ASTNode>>, Option<Vec<ASTNode>>),	block: Vec<ASTNode>) -> LazyResult {
+		let name: String = match *name {
+			ASTNode::ID{ref name, ..} => {
+				name.to_string()
+			},
+			_ => {
+				println!("Name: {:?}", &name);
+				println!("Invalid function name");
+				self.fail();
+			}
+		};
+
+		if self.scopes.len() > 1 {
+			// Declared inside a function or block -> a local closure over the variables around it,
+			// dropped with the scope like any `let`
+			let environment = self.capture_environment(&parameters, &block);
+			let function = LazyResult::Function(Rc::new(FunctionValue { name: name.clone(), parameters, block, environment }));
+
+			match self.current_scope.get(&name) {
+				Some(Some(LazyResult::Shared(cell))) => *cell.borrow_mut() = Some(function),
+				_ => { self.current_scope.insert(name, Some(function)); }
+			}
+			return LazyResult::Null;
+		}
+
 		if let Some(mut funcs) = self.functions.pop() {
-			let name: String = match *name {
-				ASTNode::ID{ref name} => {
-					name.to_string()
-				},
-				_ => {
-					println!("Name: {:?}", &name);
-					println!("Invalid function name");
-					std::process::exit(1);
-				}
-			};
 			funcs.insert(name, (parameters, block));
 
 			self.functions.push(funcs);
@@ -4827,8 +7464,27 @@ impl Executor {
 		return LazyResult::Null;
 	}
 
-	fn var_declaration(&mut self, name: &Rc<ASTNode>, value: Option<Rc<ASTNode>>) -> LazyResult {
+	fn var_declaration(&mut self, name: &Rc<ASTNode>, value: Option<Rc<ASTNode>>, eager: bool) -> LazyResult {
+		let (name, line) = match **name {
+			ASTNode::ID{ref name, line} => {
+				self.line = line;
+				(name.to_string(), line)
+			},
+			_ => {
+				println!("Name: {:?}", &name);
+				println!("Invalid variable name");
+				self.fail();
+			}
+		};
+		if let Some(stats) = self.lazy_stats.as_mut() {
+			let order = stats.len();
+			stats.entry((name.to_string(), line)).or_insert(LazyStat { order, ..Default::default() }).declared += 1;
+		}
 		let value = match value {
+			Some(value) if eager => {
+				let value = self.evaluate((*value).clone());
+				Some(self.value2_lazy(value))
+			},
 			Some(value) => {
 				match *value {
 					ASTNode::Integer{value} => Some(LazyResult::Int(value)),
@@ -4836,38 +7492,401 @@ impl Executor {
 					ASTNode::Bool{value} => Some(LazyResult::Bool(value)),
 					ASTNode::Str{ref value} => Some(LazyResult::Str(value.clone())),
 					ASTNode::None => Some(LazyResult::None),
-					_ => Some(LazyResult::Expression { expr: value.clone()})
+					// Creating a closure has no side effects, it captures its environment now
+					ASTNode::Lambda{..} => {
+						let value = self.evaluate((*value).clone());
+						Some(self.value2_lazy(value))
+					},
+					_ => self.defer(&name, line, &value)
 				}
 			},
 			_ => None
 		};
-		let name: String = match **name {
-			ASTNode::ID{ref name} => {
-				name.to_string()
-			},
-			_ => {
-				println!("Name: {:?}", &name);
-				println!("Invalid variable name");
-				std::process::exit(1);
-			}
-		};
 		
 		self.current_scope.insert(name, value);
 
 		return LazyResult::Null;
 	}
+
+	fn lazy_report(&self) {
+		// --lazy-stats -> how each binding was used, written to stderr after the program ran
+		let Some(ref stats) = self.lazy_stats else {
+			return;
+		};
+		let mut stats: Vec<(&(String, usize), &LazyStat)> = stats.iter().collect();
+		stats.sort_by_key(|(_, stat)| stat.order);
+
+		eprintln!("{:<20} {:>6} {:>8} {:>8} {:>8} {:>8} {:>12}", "binding", "line", "declared", "deferred", "forced", "reads", "time");
+		for ((name, line), stat) in &stats {
+			let time = format!("{:.3}ms", stat.time.as_secs_f64() * 1000.0);
+			eprintln!("{:<20} {:>6} {:>8} {:>8} {:>8} {:>8} {:>12}", name, line, stat.declared, stat.deferred, stat.forced, stat.reads, time);
+		}
+		let unused: Vec<String> = stats.iter()
+			.filter(|(_, stat)| stat.reads == 0)
+			.map(|((name, line), _)| format!("{name} (line {line})"))
+			.collect();
+
+		if !unused.is_empty() {
+			eprintln!("Never used: {}", unused.join(", "));
+		}
+	}
+
+	fn count_read(&mut self, name: &str, line: usize) {
+		// --lazy-stats -> a read of `name` at `line`, counted for the `let` the Resolver bound it to
+		let Some(stats) = self.lazy_stats.as_mut() else {
+			return;
+		};
+		if let Some(declared) = self.declarations.get(&(name.to_string(), line)) {
+			if let Some(stat) = stats.get_mut(&(name.to_string(), *declared)) {
+				stat.reads += 1;
+			}
+		}
+	}
+
+	fn defer(&mut self, name: &str, line: usize, value: &Rc<ASTNode>) -> Option<LazyResult> {
+		// let x = a * 2 + 1; -> constant parts are folded now, the rest becomes a thunk
+		let value = self.fold(value);
+
+		if let ASTNode::Constant{ref value} = *value {
+			return Some(self.value2_lazy(value.clone()));
+		}
+		// Captured now, so the expression means what it would mean if evaluated here
+		let mut environment = self.capture_names(&value);
+		let mut depth = 0;
+
+		for binding in environment.values_mut() {
+			if let Some(LazyResult::Expression{memo, depth: inner, ..}) = binding {
+				// A thunk that was already forced is captured as its value
+				let forced = memo.borrow().clone();
+				match forced {
+					Some(forced) => *binding = Some(self.value2_lazy(forced)),
+					None => depth = depth.max(*inner)
+				}
+			}
+		}
+		if depth + 1 > THUNK_DEPTH_LIMIT {
+			// Forcing here keeps long scripts from building one deep chain of deferred expressions
+			let value = self.evaluate((*value).clone());
+			return Some(self.value2_lazy(value));
+		}
+		if let Some(stat) = self.lazy_stats.as_mut().and_then(|stats| stats.get_mut(&(name.to_string(), line))) {
+			stat.deferred += 1;
+		}
+		Some(LazyResult::Expression {
+			expr: value,
+			environment: Rc::new(RefCell::new(environment)),
+			memo: Rc::new(RefCell::new(None)),
+			depth: depth + 1,
+			declared: line
+		})
+	}
+
+	fn fold(&mut self, node: &Rc<ASTNode>) -> Rc<ASTNode> {
+		// Replaces names bound to known scalars by their value and computes operations on constants.
+		// Only what cannot fail is computed, errors still surface when the binding is read.
+		// Lambdas and blocks are left alone, their names are looked up when they run
+		match **node {
+			ASTNode::Integer{value} => Rc::new(ASTNode::Constant{ value: self.lazy2_value(LazyResult::Int(value)) }),
+			ASTNode::Float{value} => Rc::new(ASTNode::Constant{ value: self.lazy2_value(LazyResult::Float(value)) }),
+			ASTNode::Bool{value} => Rc::new(ASTNode::Constant{ value: self.lazy2_value(LazyResult::Bool(value)) }),
+			ASTNode::Str{ref value} => Rc::new(ASTNode::Constant{ value: self.lazy2_value(LazyResult::Str(value.clone())) }),
+			ASTNode::ID{ref name, line} => {
+				let known = match self.find_variable(name) {
+					Some(Some(LazyResult::Expression{memo, ..})) => memo.borrow().clone().map(|value| self.value2_lazy(value)),
+					Some(known) => known,
+					None => None
+				};
+				match known {
+					Some(value @ (LazyResult::Int(..) | LazyResult::Float(..) | LazyResult::Bool(..) | LazyResult::Str(..))) => {
+						// Read here, the constant replaces the name
+						self.count_read(name, line);
+						Rc::new(ASTNode::Constant{ value: self.lazy2_value(value) })
+					},
+					_ => node.clone()
+				}
+			},
+			ASTNode::UnaryOperation{ref operand, ref operator} if ["-", "+", "!"].contains(&operator.as_str()) => {
+				let operand = self.fold(operand);
+
+				if let ASTNode::Constant{ref value} = *operand {
+					let foldable = match (self.value2_lazy(value.clone()), operator.as_str()) {
+						(LazyResult::Int(value), "-") => value.checked_neg().is_some(),
+						(LazyResult::Int(..) | LazyResult::Float(..), "-" | "+") => true,
+						(LazyResult::Bool(..), "!") => true,
+						_ => false
+					};
+					if foldable {
+						let value = self.evaluate_unary_expression(operator.to_string(), operand.clone());
+						return Rc::new(ASTNode::Constant{ value });
+					}
+				}
+				Rc::new(ASTNode::UnaryOperation{ operand, operator: operator.to_string() })
+			},
+			ASTNode::BinaryOperation{ref left, ref operation, ref right} => {
+				let left = self.fold(left);
+				let right = self.fold(right);
+
+				if let (ASTNode::Constant{value: ref l}, ASTNode::Constant{value: ref r}) = (&*left, &*right) {
+					let l = self.value2_lazy(l.clone());
+					let r = self.value2_lazy(r.clone());
+
+					if foldable(&l, operation, &r) {
+						let value = self.evaluate_binary_expression(left.clone(), operation.to_string(), right.clone());
+						return Rc::new(ASTNode::Constant{ value });
+					}
+				}
+				Rc::new(ASTNode::BinaryOperation{ left, operation: operation.to_string(), right })
+			},
+			ASTNode::ExpressionList{ref list} => {
+				let list = list.iter().map(|item| (*self.fold(&Rc::new(item.clone()))).clone()).collect();
+				Rc::new(ASTNode::ExpressionList{ list })
+			},
+			ASTNode::Index{ref object, ref index} => {
+				Rc::new(ASTNode::Index{ object: self.fold(object), index: self.fold(index) })
+			},
+			ASTNode::FunctionCall{ref name, ref args} => {
+				let args = args.iter().map(|arg| (*self.fold(&Rc::new(arg.clone()))).clone()).collect();
+				Rc::new(ASTNode::FunctionCall{ name: name.clone(), args })
+			},
+			ASTNode::Keyword{ref name, ref value} => {
+				Rc::new(ASTNode::Keyword{ name: name.to_string(), value: self.fold(value) })
+			},
+			ASTNode::Spread{ref value} => Rc::new(ASTNode::Spread{ value: self.fold(value) }),
+			_ => node.clone()
+		}
+	}
 		
 }
 
+fn foldable(left: &LazyResult, operation: &str, right: &LazyResult) -> bool {
+	// Whether `left operation right` on constants is computed without any chance of a runtime error
+	match (left, right) {
+		(LazyResult::Int(l), LazyResult::Int(r)) => match operation {
+			"+" => l.checked_add(*r).is_some(),
+			"-" => l.checked_sub(*r).is_some(),
+			"*" => l.checked_mul(*r).is_some(),
+			"/" => l.checked_div(*r).is_some(),
+			"%" => l.checked_rem(*r).is_some(),
+			"<" | "<=" | ">" | ">=" | "==" | "!=" => true,
+			_ => false
+		},
+		(LazyResult::Float(..), LazyResult::Float(..)) => {
+			["+", "-", "*", "/", "<", "<=", ">", ">=", "==", "!="].contains(&operation)
+		},
+		(LazyResult::Str(..), LazyResult::Str(..)) => operation == "+",
+		(LazyResult::Bool(..), LazyResult::Bool(..)) => operation == "&&" || operation == "||",
+		_ => false
+	}
+}
+
+fn contains_yield(block: &[ASTNode]) -> bool {
+	// A function is a generator when its own body (not a nested function) uses `yield`
+	block.iter().any(|statement| match statement {
+		ASTNode::Yield{..} => true,
+		ASTNode::If{if_block, else_block, ..} => {
+			contains_yield(if_block) || else_block.as_deref().is_some_and(contains_yield)
+		},
+		ASTNode::While{body, ..} | ASTNode::For{body, ..} | ASTNode::Loop{body, ..} => contains_yield(body),
+		ASTNode::Match{cases, ..} => contains_yield(cases),
+		ASTNode::Option{block, ..} | ASTNode::Catch{block, ..} => contains_yield(block),
+		ASTNode::Try{body, catches, finally} => {
+			contains_yield(body) || contains_yield(catches) || finally.as_deref().is_some_and(contains_yield)
+		},
+		_ => false
+	})
+}
+
+fn referenced_names(node: &ASTNode, names: &mut Vec<String>) {
+	// Every identifier read or assigned anywhere in `node`, nested blocks and lambdas included
+	if let ASTNode::ID{name, ..} = node {
+		if !names.contains(name) {
+			names.push(name.to_string());
+		}
+		return;
+	}
+	let mut visit = |node: &ASTNode| referenced_names(node, names);
+
+	match node {
+		ASTNode::PropertyAccess{object, ..} => visit(object),
+		ASTNode::Index{object, index} => {
+			visit(object);
+			visit(index);
+		},
+		ASTNode::UnaryOperation{operand, ..} => visit(operand),
+		ASTNode::BinaryOperation{left, right, ..} => {
+			visit(left);
+			visit(right);
+		},
+		ASTNode::Keyword{value, ..} | ASTNode::Spread{value} | ASTNode::Yield{value} => visit(value),
+		ASTNode::Flow{result: Some(value), ..} | ASTNode::Throw{value, ..} => visit(value),
+		ASTNode::Var{value: Some(value), ..} => visit(value),
+		ASTNode::Assign{name, value} => {
+			visit(name);
+			visit(value);
+		},
+		ASTNode::FunctionCall{name, args} => {
+			visit(name);
+			args.iter().for_each(visit);
+		},
+		ASTNode::ExpressionList{list} | ASTNode::Return{list} => list.iter().for_each(visit),
+		ASTNode::If{condition, if_block, else_block} => {
+			visit(condition);
+			if_block.iter().chain(else_block.iter().flatten()).for_each(visit);
+		},
+		ASTNode::While{condition: object, body, ..} | ASTNode::For{object, body, ..} => {
+			visit(object);
+			body.iter().for_each(visit);
+		},
+		ASTNode::Loop{body, ..} => body.iter().for_each(visit),
+		ASTNode::Match{option, cases} => {
+			visit(option);
+			cases.iter().for_each(visit);
+		},
+		ASTNode::Option{condition, block} => condition.iter().chain(block.iter()).for_each(visit),
+		ASTNode::Try{body, catches, finally} => body.iter().chain(catches.iter()).chain(finally.iter().flatten()).for_each(visit),
+		ASTNode::Catch{block, ..} => block.iter().for_each(visit),
+		ASTNode::Lambda{parameters, block, ..} | ASTNode::Function{parameters, block, ..} => {
+			parameters.0.iter().flatten().chain(parameters.1.iter().flatten()).for_each(&mut visit);
+			block.iter().for_each(visit);
+		},
+		_ => ()
+	}
+}
+
+fn assigned_names(node: &ASTNode, names: &mut Vec<String>) {
+	// Every name assigned to or changed with `++` / `--` anywhere in `node`
+	let target = match node {
+		ASTNode::Assign{name, ..} => Some(name),
+		ASTNode::UnaryOperation{operand, operator} if operator == "++" || operator == "--" => Some(operand),
+		_ => None
+	};
+	if let Some(ASTNode::ID{name, ..}) = target.map(|target| &**target) {
+		if !names.contains(name) {
+			names.push(name.to_string());
+		}
+	}
+	let mut visit = |node: &ASTNode| assigned_names(node, names);
+
+	match node {
+		ASTNode::PropertyAccess{object, ..} => visit(object),
+		ASTNode::Index{object: left, index: right} | ASTNode::BinaryOperation{left, right, ..} => {
+			visit(left);
+			visit(right);
+		},
+		ASTNode::UnaryOperation{operand: value, ..} | ASTNode::Keyword{value, ..} | ASTNode::Spread{value} | ASTNode::Yield{value}
+		| ASTNode::Flow{result: Some(value), ..} | ASTNode::Throw{value, ..} | ASTNode::Var{value: Some(value), ..}
+		| ASTNode::Assign{value, ..} => visit(value),
+		ASTNode::FunctionCall{name, args} => {
+			visit(name);
+			args.iter().for_each(visit);
+		},
+		ASTNode::ExpressionList{list} | ASTNode::Return{list} => list.iter().for_each(visit),
+		ASTNode::If{condition, if_block, else_block} => {
+			visit(condition);
+			if_block.iter().chain(else_block.iter().flatten()).for_each(visit);
+		},
+		ASTNode::While{condition: object, body, ..} | ASTNode::For{object, body, ..} => {
+			visit(object);
+			body.iter().for_each(visit);
+		},
+		ASTNode::Loop{body, ..} | ASTNode::Impl{block: body, ..} => body.iter().for_each(visit),
+		ASTNode::Match{option, cases} => {
+			visit(option);
+			cases.iter().for_each(visit);
+		},
+		ASTNode::Option{condition, block} => condition.iter().chain(block.iter()).for_each(visit),
+		ASTNode::Try{body, catches, finally} => body.iter().chain(catches.iter()).chain(finally.iter().flatten()).for_each(visit),
+		ASTNode::Catch{block, ..} => block.iter().for_each(visit),
+		ASTNode::Lambda{parameters, block, ..} | ASTNode::Function{parameters, block, ..} => {
+			parameters.0.iter().flatten().chain(parameters.1.iter().flatten()).for_each(&mut visit);
+			block.iter().for_each(visit);
+		},
+		_ => ()
+	}
+}
+
+fn declared_names(block: &Vec<ASTNode>, names: &mut Vec<String>) {
+	// Every name a block declares, in nested blocks too but not inside nested functions
+	for statement in block {
+		match statement {
+			ASTNode::Var{name, ..} | ASTNode::Function{name, ..} => {
+				if let ASTNode::ID{name, ..} = &**name {
+					names.push(name.to_string());
+				}
+			},
+			ASTNode::For{loop_var, body, ..} => {
+				if let ASTNode::ID{name, ..} = &**loop_var {
+					names.push(name.to_string());
+				}
+				declared_names(body, names);
+			},
+			ASTNode::If{if_block, else_block, ..} => {
+				declared_names(if_block, names);
+				declared_names(else_block.as_ref().unwrap_or(&vec![]), names);
+			},
+			ASTNode::While{body, ..} | ASTNode::Loop{body, ..} => declared_names(body, names),
+			ASTNode::Try{body, catches, finally} => {
+				declared_names(body, names);
+				for clause in catches {
+					if let ASTNode::Catch{name, block, ..} = clause {
+						if let Some(ASTNode::ID{name, ..}) = name.as_deref() {
+							names.push(name.to_string());
+						}
+						declared_names(block, names);
+					}
+				}
+				declared_names(finally.as_ref().unwrap_or(&vec![]), names);
+			},
+			ASTNode::Match{cases, ..} => {
+				for case in cases {
+					if let ASTNode::Option{block, ..} = case {
+						declared_names(block, names);
+					}
+				}
+			},
+			_ => ()
+		}
+	}
+}
+
 use std::fs::File;
 use std::io::prelude::*;
 use std::env::{self, Args};
+use std::thread;
 
 fn main() {
     let mut args: Args = env::args();
     args.next();
 
-    let file_name = match args.next() {
+    // mar [--max-depth=N] [--eval=lazy|eager] [--lazy-stats] [--warn-eager] file.mar
+    let mut file_name: Option<String> = None;
+    let mut options = Options { max_call_depth: 1000, eager: false, lazy_stats: false, warn_eager: false };
+
+    for arg in args {
+        if let Some(depth) = arg.strip_prefix("--max-depth=") {
+            options.max_call_depth = match depth.parse() {
+                Ok(depth) if depth <= MAX_CALL_DEPTH => depth,
+                Ok(_) => {
+                    println!("Invalid --max-depth `{depth}`, at most {MAX_CALL_DEPTH} is supported");
+                    std::process::exit(1);
+                },
+                Err(_) => {
+                    println!("Invalid --max-depth `{depth}`, expected a positive number");
+                    std::process::exit(1);
+                }
+            };
+        } else if let Some(mode) = arg.strip_prefix("--eval=") {
+            options.eager = eval_mode(mode);
+        } else if arg == "--lazy-stats" {
+            options.lazy_stats = true;
+        } else if arg == "--warn-eager" {
+            options.warn_eager = true;
+        } else {
+            file_name = Some(arg);
+        }
+    }
+
+    let file_name = match file_name {
         Some(c) => c,
         None => {
             println!("Source file not provided");
@@ -4891,6 +7910,65 @@ fn main() {
             std::process::exit(1);
         }
     };
+    // The interpreter recurses on the Rust stack, give it room for `max_call_depth` calls
+    let stack_size = (64 + options.max_call_depth / 8).min(MAX_STACK_MB) * 1024 * 1024;
+    let interpreter = match thread::Builder::new().stack_size(stack_size).spawn(move || run(code, options)) {
+        Ok(interpreter) => interpreter,
+        Err(e) => {
+            println!("Could not start the interpreter with a {}MB stack: {e}", stack_size / (1024 * 1024));
+            std::process::exit(1);
+        }
+    };
+
+    if interpreter.join().is_err() {
+        std::process::exit(1);
+    }
+}
+
+// Stack of the interpreter thread in MB, 64 plus 1 for every 8 calls allowed
+const MAX_STACK_MB: usize = 4096;
+const MAX_CALL_DEPTH: usize = (MAX_STACK_MB - 64) * 8;
+
+struct Options {
+    max_call_depth: usize,
+    eager: bool,        // --eval=eager, or a `#! eval=eager` pragma in the file
+    lazy_stats: bool,   // --lazy-stats
+    warn_eager: bool,   // --warn-eager, list the bindings made eager because of side effects
+}
+
+fn eval_mode(mode: &str) -> bool {
+    match mode {
+        "lazy" => false,
+        "eager" => true,
+        _ => {
+            println!("Invalid evaluation mode `{mode}`, expected `lazy` or `eager`");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn eval_pragma(code: &str) -> Option<bool> {
+    // `#! eval=eager` among the comments at the top of the file
+    for line in code.lines().map(|line| line.trim()) {
+        if !line.starts_with('#') && !line.is_empty() {
+            break;
+        }
+        let pragma: String = line.trim_start_matches("#!").chars().filter(|chr| !chr.is_whitespace()).collect();
+
+        if line.starts_with("#!") {
+            if let Some(mode) = pragma.strip_prefix("eval=") {
+                return Some(eval_mode(mode));
+            }
+        }
+    }
+    None
+}
+
+fn run(code: String, mut options: Options) {
+    // The file's pragma wins over --eval, `lazy let` / `eager let` over both
+    if let Some(eager) = eval_pragma(&code) {
+        options.eager = eager;
+    }
     let mut lexer = Lexer::new(code);
     let mut tokens = lexer.lex();
 
@@ -4899,9 +7977,58 @@ fn main() {
     let mut parser = Parser::new(tokens);
     let ast = parser.parse();
 
+    let mut checker = TypeChecker::new();
+    let errors = checker.check(&ast);
+
+    if !errors.is_empty() {
+        for error in errors {
+            println!("{error}");
+        }
+        std::process::exit(1);
+    }
+
+    let mut resolver = Resolver::new();
+    let errors = resolver.resolve(&ast);
+
+    if !errors.is_empty() {
+        for error in errors {
+            println!("{error}");
+        }
+        std::process::exit(1);
+    }
+    for warning in resolver.warnings {
+        eprintln!("{warning}");
+    }
+    let declarations = resolver.declarations;
+
+    let mut purity = Purity::new();
+    let ast = purity.mark(ast);
+
+    if options.warn_eager && !options.eager {
+        for warning in purity.warnings {
+            eprintln!("{warning}");
+        }
+    }
+
     //println!("{:?}", ast);
+    // Runtime errors unwind to the `try` catching them, they are not panics to report
+    let report = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if !info.payload().is::<MarError>() {
+            report(info);
+        }
+    }));
+
     let mut exec = Executor::new(ast);
+    exec.max_call_depth = options.max_call_depth;
+    exec.eager = options.eager;
+
+    if options.lazy_stats {
+        exec.lazy_stats = Some(HashMap::new());
+        exec.declarations = declarations;
+    }
     exec.execute();
+    exec.lazy_report();
 
     //println!("All variables\n");
     //println!("{:#?}", exec.scopes);